@@ -0,0 +1,74 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use mu::matrix::Mat;
+
+/// Timed runs per kernel and size, after one untimed warm-up run.
+const RUNS: usize = 5;
+
+fn random(size: usize, seed: u64) -> Mat {
+    let mut state = seed;
+    let data = (0..size * size)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64 - 0.5
+        })
+        .collect();
+    Mat::from_vec(size, size, data)
+}
+
+/// The textbook triple loop the blocked kernel replaced.
+fn naive(a: &Mat, b: &Mat) -> Mat {
+    let n = a.shape().0;
+    let mut c = Mat::zeros(n, n);
+    for i in 0..n {
+        for j in 0..n {
+            c[(i, j)] = (0..n).map(|k| a[(i, k)] * b[(k, j)]).sum();
+        }
+    }
+    c
+}
+
+/// Best of `RUNS` timings after a warm-up, which filters out cold caches and
+/// scheduler noise better than a single run or the mean.
+fn time<F: FnMut() -> Mat>(mut f: F) -> Duration {
+    black_box(f());
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn main() {
+    // The blocked column times whichever kernel `*` uses in this build, so
+    // run once with and once without `--features parallel` to see what
+    // threading adds over the serial kernel.
+    let kernel = if cfg!(feature = "parallel") {
+        "parallel"
+    } else {
+        "serial"
+    };
+    println!(
+        "{:>6} {:>12} {:>12} {:>8}",
+        "size", "naive", kernel, "speedup"
+    );
+    for size in [256, 512, 1024] {
+        let a = random(size, 1);
+        let b = random(size, 2);
+        let naive_time = time(|| naive(&a, &b));
+        let blocked_time = time(|| &a * &b);
+        println!(
+            "{:>6} {:>12.2?} {:>12.2?} {:>7.1}x",
+            size,
+            naive_time,
+            blocked_time,
+            naive_time.as_secs_f64() / blocked_time.as_secs_f64()
+        );
+    }
+}
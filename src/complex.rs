@@ -1,8 +1,18 @@
 use std::f64;
 use std::fmt;
+use std::iter;
 use std::ops;
 
-#[derive(Debug, Clone)]
+use rand::Rng;
+
+use crate::format::pad;
+use crate::scalar::Scalar;
+
+mod parse;
+
+pub use parse::{ParseComplexError, ParseComplexErrorKind};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct Complex {
     pub re: f64,
     pub im: f64,
@@ -10,11 +20,71 @@ pub struct Complex {
 
 impl Complex {
     pub fn new(re: f64, im: f64) -> Self{
-        Complex { re: re, im: im }
+        Complex { re, im }
+    }
+
+    pub const fn zero() -> Self {
+        Complex { re: 0.0, im: 0.0 }
+    }
+
+    pub const fn one() -> Self {
+        Complex { re: 1.0, im: 0.0 }
+    }
+
+    /// The imaginary unit.
+    pub const fn i() -> Self {
+        Complex { re: 0.0, im: 1.0 }
+    }
+
+    pub fn from_polar(r: f64, theta: f64) -> Self {
+        Complex { re: r * theta.cos(), im: r * theta.sin() }
+    }
+
+    /// `(abs, arg)`, the inverse of `from_polar` with `arg` in `(-π, π]`.
+    pub fn to_polar(&self) -> (f64, f64) {
+        (self.abs(), self.arg())
+    }
+
+    /// `cos θ + i sin θ`, the point on the unit circle at angle `theta`.
+    pub fn cis(theta: f64) -> Self {
+        Complex { re: theta.cos(), im: theta.sin() }
+    }
+
+    /// The `n` complex `n`-th roots of 1, `cis(2πk / n)` for `k` in `0..n`.
+    pub fn roots_of_unity(n: u32) -> Vec<Self> {
+        (0..n)
+            .map(|k| Complex::cis(f64::consts::TAU * k as f64 / n as f64))
+            .collect()
+    }
+
+    /// Squared modulus `re² + im²`, cheaper than `abs` when only comparing
+    /// magnitudes.
+    pub fn norm_sqr(&self) -> f64 {
+        self.re * self.re + self.im * self.im
     }
 
+    /// Same direction with modulus 1. Zero has no direction and is returned
+    /// unchanged.
+    pub fn normalize(&self) -> Self {
+        let abs = self.abs();
+        if abs == 0.0 {
+            return *self;
+        }
+        *self / abs
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    pub fn is_nan(&self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+
+    /// Modulus via `hypot`, which neither overflows for huge parts nor
+    /// underflows for tiny ones.
     pub fn abs(&self) -> f64 {
-        (self.re * self.re + self.im * self.im).sqrt()
+        self.re.hypot(self.im)
     }
 
     pub fn arg(&self) -> f64 {
@@ -34,23 +104,246 @@ impl Complex {
         Complex { re: f64::ln(self.abs()), im: self.arg() }
     }
 
+    /// Real power on the principal branch. Integer exponents go through
+    /// `powi`, which is exact for small Gaussian integers.
     pub fn pow(&self, n: f64) -> Self {
+        if n.fract() == 0.0 && n.abs() <= i32::MAX as f64 {
+            return self.powi(n as i32);
+        }
         let abs = self.abs();
         let arg = self.arg();
         let new_abs = abs.powf(n);
         let new_arg = arg * n;
         Complex { re: new_abs * f64::cos(new_arg), im: new_abs * f64::sin(new_arg) }
     }
+
+    /// Principal square root, with a non-negative real part.
+    pub fn sqrt(&self) -> Self {
+        let r = self.abs();
+        if r == 0.0 {
+            return Complex { re: 0.0, im: self.im };
+        }
+        // Avoids the cancellation in `sqrt((r - re) / 2)` when `re > 0`.
+        let t = ((self.re.abs() + r) / 2.0).sqrt();
+        if self.re >= 0.0 {
+            Complex { re: t, im: self.im / (2.0 * t) }
+        } else {
+            Complex { re: self.im.abs() / (2.0 * t), im: t.copysign(self.im) }
+        }
+    }
+
+    /// Principal cube root, `|z|^(1/3) * e^(i arg(z) / 3)`. Note that the
+    /// principal cube root of a negative real number is not real.
+    pub fn cbrt(&self) -> Self {
+        Complex::from_polar(self.abs().cbrt(), self.arg() / 3.0)
+    }
+
+    /// All `n` distinct `n`-th roots, starting from the principal one and
+    /// going counter-clockwise.
+    pub fn nth_roots(&self, n: u32) -> Vec<Self> {
+        let r = self.abs().powf(1.0 / n as f64);
+        let arg = self.arg();
+        (0..n)
+            .map(|k| Complex::from_polar(r, (arg + f64::consts::TAU * k as f64) / n as f64))
+            .collect()
+    }
+
+    /// Logarithm to a real base.
+    pub fn log(&self, base: f64) -> Self {
+        self.ln() / base.ln()
+    }
+
+    /// Complex power `z^w = e^(w ln z)` on the principal branch. For `z = 0`
+    /// the result is 1 if `w = 0`, 0 if `Re(w) > 0`, infinity if `Re(w) < 0`
+    /// and NaN otherwise, since `|0^w|` has no limit when `w` is imaginary.
+    pub fn powc(&self, w: Complex) -> Self {
+        if self.re == 0.0 && self.im == 0.0 {
+            return if w.re == 0.0 && w.im == 0.0 {
+                Complex::new(1.0, 0.0)
+            } else if w.re > 0.0 {
+                Complex::new(0.0, 0.0)
+            } else if w.re < 0.0 {
+                Complex::new(f64::INFINITY, 0.0)
+            } else {
+                Complex::new(f64::NAN, f64::NAN)
+            };
+        }
+        (w * self.ln()).exp()
+    }
+
+    /// Integer power by repeated squaring, inverting for negative `n`.
+    pub fn powi(&self, n: i32) -> Self {
+        let mut base = *self;
+        let mut result = Complex::new(1.0, 0.0);
+        let mut k = n.unsigned_abs();
+        while k > 0 {
+            if k & 1 == 1 {
+                result *= base;
+            }
+            base = base * base;
+            k >>= 1;
+        }
+        if n < 0 { 1.0 / result } else { result }
+    }
+
+    pub fn sin(&self) -> Self {
+        Complex { re: self.re.sin() * self.im.cosh(), im: self.re.cos() * self.im.sinh() }
+    }
+
+    pub fn cos(&self) -> Self {
+        Complex { re: self.re.cos() * self.im.cosh(), im: -self.re.sin() * self.im.sinh() }
+    }
+
+    /// `tan(z) = -i tanh(iz)`, which inherits the overflow-free evaluation
+    /// of [`Complex::tanh`].
+    pub fn tan(&self) -> Self {
+        let t = Complex { re: -self.im, im: self.re }.tanh();
+        Complex { re: t.im, im: -t.re }
+    }
+
+    pub fn sinh(&self) -> Self {
+        Complex { re: self.re.sinh() * self.im.cos(), im: self.re.cosh() * self.im.sin() }
+    }
+
+    pub fn cosh(&self) -> Self {
+        Complex { re: self.re.cosh() * self.im.cos(), im: self.re.sinh() * self.im.sin() }
+    }
+
+    /// Kahan's formulation, which never forms `cosh(2 re)`. Past |re| = 22
+    /// `tanh(re)` rounds to ±1 and only the tiny imaginary part is left.
+    pub fn tanh(&self) -> Self {
+        if self.re.abs() > 22.0 {
+            let im = 4.0 * self.im.sin() * self.im.cos() * (-2.0 * self.re.abs()).exp();
+            return Complex { re: 1.0_f64.copysign(self.re), im };
+        }
+        let t = self.im.tan();
+        let beta = 1.0 + t * t;
+        let s = self.re.sinh();
+        let rho = (1.0 + s * s).sqrt();
+        let d = 1.0 + beta * s * s;
+        Complex { re: beta * rho * s / d, im: t / d }
+    }
+
+    /// `asin(z) = -i ln(iz + sqrt(1 - z) sqrt(1 + z))`. Splitting the root
+    /// keeps the sign of a zero imaginary part, so real arguments beyond
+    /// ±1 land on the same side of the branch cut as in C99's `casin`.
+    pub fn asin(&self) -> Self {
+        let iz = Complex { re: -self.im, im: self.re };
+        let one_minus = Complex { re: 1.0 - self.re, im: -self.im };
+        let one_plus = Complex { re: 1.0 + self.re, im: self.im };
+        let w = (iz + one_minus.sqrt() * one_plus.sqrt()).ln();
+        Complex { re: w.im, im: -w.re }
+    }
+
+    /// `acos(z) = π/2 - asin(z)`.
+    pub fn acos(&self) -> Self {
+        let w = self.asin();
+        Complex { re: f64::consts::FRAC_PI_2 - w.re, im: -w.im }
+    }
+
+    /// `atan(z) = i/2 (ln(1 - iz) - ln(1 + iz))`.
+    pub fn atan(&self) -> Self {
+        let iz = Complex { re: -self.im, im: self.re };
+        let one = Complex::new(1.0, 0.0);
+        let w = (one - iz).ln() - (one + iz).ln();
+        Complex { re: -w.im / 2.0, im: w.re / 2.0 }
+    }
+
+    /// `asinh(z) = ln(z + sqrt(z² + 1))`, evaluated in the right half-plane
+    /// and mirrored by `asinh(-z) = -asinh(z)`, since the sum cancels for
+    /// `Re z < 0`. Inside the unit disk the logarithm is taken as
+    /// `ln(1 + t)` with `t = z + z² / (sqrt(z² + 1) + 1)` so that tiny
+    /// arguments are not rounded away against the 1.
+    pub fn asinh(&self) -> Self {
+        if self.re < 0.0 {
+            return -(-*self).asinh();
+        }
+        let root = (*self * *self + Complex::new(1.0, 0.0)).sqrt();
+        if self.abs() >= 1.0 {
+            return (*self + root).ln();
+        }
+        let t = *self + *self * *self / (root + Complex::new(1.0, 0.0));
+        Complex { re: 0.5 * (t.re * (2.0 + t.re) + t.im * t.im).ln_1p(), im: t.im.atan2(1.0 + t.re) }
+    }
+
+    /// `acosh(z) = ln(z + sqrt(z + 1) sqrt(z - 1))`.
+    pub fn acosh(&self) -> Self {
+        let one = Complex::new(1.0, 0.0);
+        (*self + (*self + one).sqrt() * (*self - one).sqrt()).ln()
+    }
+
+    /// `atanh(z) = (ln(1 + z) - ln(1 - z)) / 2`, with the real part taken as
+    /// `log1p(4x / ((1 - x)² + y²)) / 4` and the imaginary part as
+    /// `atan2(2y, (1 - x)(1 + x) - y²) / 2` so that tiny arguments keep their
+    /// digits.
+    pub fn atanh(&self) -> Self {
+        let (x, y) = (self.re, self.im);
+        let re = (4.0 * x / ((1.0 - x) * (1.0 - x) + y * y)).ln_1p() / 4.0;
+        let im = (2.0 * y).atan2((1.0 - x) * (1.0 + x) - y * y) / 2.0;
+        Complex { re, im }
+    }
+
+    /// Point on the unit circle with a uniformly distributed angle.
+    pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Complex::cis(rng.gen_range(0.0..f64::consts::TAU))
+    }
+}
+
+impl Scalar for Complex {
+    fn zero() -> Self {
+        Complex::zero()
+    }
+
+    fn one() -> Self {
+        Complex::one()
+    }
+
+    fn abs(self) -> f64 {
+        Complex::abs(&self)
+    }
+}
+
+impl Complex {
+    /// Writes `a + bi` with both parts rendered by `part`, then pads the
+    /// whole to the formatter's width.
+    fn fmt_parts(&self, f: &mut fmt::Formatter, part: impl Fn(f64) -> String) -> fmt::Result {
+        let s = if self.im.is_sign_negative() {
+            format!("{} - {}i", part(self.re), part(-self.im))
+        } else {
+            format!("{} + {}i", part(self.re), part(self.im))
+        };
+        let width = f.width().unwrap_or(0);
+        f.write_str(&pad(f, &s, width))
+    }
 }
 
 impl fmt::Display for Complex {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        if self.im < 0.0 {
-            write!(f, "{} - {}i", self.re, -self.im)?;
-        } else {
-            write!(f, "{} + {}i", self.re, self.im)?;
-        }
-        Ok(())
+        let precision = f.precision();
+        self.fmt_parts(f, |x| match precision {
+            Some(p) => format!("{:.*}", p, x),
+            None => x.to_string(),
+        })
+    }
+}
+
+impl fmt::LowerExp for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision();
+        self.fmt_parts(f, |x| match precision {
+            Some(p) => format!("{:.*e}", p, x),
+            None => format!("{:e}", x),
+        })
+    }
+}
+
+impl fmt::UpperExp for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision();
+        self.fmt_parts(f, |x| match precision {
+            Some(p) => format!("{:.*E}", p, x),
+            None => format!("{:E}", x),
+        })
     }
 }
 
@@ -70,6 +363,38 @@ impl ops::Sub for Complex {
     }
 }
 
+impl ops::Add<f64> for Complex {
+    type Output = Complex;
+
+    fn add(self, other: f64) -> Complex {
+        Complex { re: self.re + other, im: self.im }
+    }
+}
+
+impl ops::Add<Complex> for f64 {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        Complex { re: self + other.re, im: other.im }
+    }
+}
+
+impl ops::Sub<f64> for Complex {
+    type Output = Complex;
+
+    fn sub(self, other: f64) -> Complex {
+        Complex { re: self.re - other, im: self.im }
+    }
+}
+
+impl ops::Sub<Complex> for f64 {
+    type Output = Complex;
+
+    fn sub(self, other: Complex) -> Complex {
+        Complex { re: self - other.re, im: -other.im }
+    }
+}
+
 impl ops::Neg for Complex {
     type Output = Complex;
 
@@ -84,7 +409,7 @@ impl ops::Mul for Complex {
     fn mul(self, other: Complex) -> Complex {
         let re = self.re * other.re - self.im * other.im;
         let im = self.re * other.im + self.im * other.re;
-        Complex { re: re, im: im }
+        Complex { re, im }
     }
 }
 
@@ -107,11 +432,27 @@ impl ops::Mul<Complex> for f64 {
 impl ops::Div for Complex {
     type Output = Complex;
 
+    /// Smith's algorithm: scales by the ratio of the divisor's parts instead
+    /// of forming `re² + im²`, which would overflow or underflow long before
+    /// the quotient does. Dividing by zero divides each part by that zero,
+    /// like `Div<f64>`.
     fn div(self, other: Complex) -> Complex {
-        let denominator = other.re * other.re + other.im * other.im;
-        let re = (self.re * other.re + self.im * other.im) / denominator;
-        let im = (self.im * other.re - self.re * other.im) / denominator;
-        Complex { re: re, im: im }
+        if other.re == 0.0 && other.im == 0.0 {
+            return self / other.re;
+        }
+        if other.re.abs() >= other.im.abs() {
+            let r = other.im / other.re;
+            let denominator = other.re + other.im * r;
+            let re = (self.re + self.im * r) / denominator;
+            let im = (self.im - self.re * r) / denominator;
+            Complex { re, im }
+        } else {
+            let r = other.re / other.im;
+            let denominator = other.re * r + other.im;
+            let re = (self.re * r + self.im) / denominator;
+            let im = (self.im * r - self.re) / denominator;
+            Complex { re, im }
+        }
     }
 }
 
@@ -127,52 +468,162 @@ impl ops::Div<Complex> for f64 {
     type Output = Complex;
 
     fn div(self, other: Complex) -> Complex {
-        let denominator = other.re * other.re + other.im * other.im;
-        let re = self * other.re / denominator;
-        let im = -self * other.im / denominator;
-        Complex { re: re, im: im }
+        Complex::from(self) / other
+    }
+}
+
+impl ops::Neg for &Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        -*self
+    }
+}
+
+/// Implements `op` for every mix of owned and borrowed operands on top of
+/// the by-value impl.
+macro_rules! forward_ref_binop {
+    ($op:ident, $method:ident, $lhs:ty, $rhs:ty) => {
+        impl ops::$op<$rhs> for &$lhs {
+            type Output = Complex;
+
+            fn $method(self, other: $rhs) -> Complex {
+                ops::$op::$method(*self, other)
+            }
+        }
+
+        impl ops::$op<&$rhs> for $lhs {
+            type Output = Complex;
+
+            fn $method(self, other: &$rhs) -> Complex {
+                ops::$op::$method(self, *other)
+            }
+        }
+
+        impl ops::$op<&$rhs> for &$lhs {
+            type Output = Complex;
+
+            fn $method(self, other: &$rhs) -> Complex {
+                ops::$op::$method(*self, *other)
+            }
+        }
+    };
+}
+
+forward_ref_binop!(Add, add, Complex, Complex);
+forward_ref_binop!(Sub, sub, Complex, Complex);
+forward_ref_binop!(Mul, mul, Complex, Complex);
+forward_ref_binop!(Div, div, Complex, Complex);
+forward_ref_binop!(Add, add, Complex, f64);
+forward_ref_binop!(Sub, sub, Complex, f64);
+forward_ref_binop!(Mul, mul, Complex, f64);
+forward_ref_binop!(Div, div, Complex, f64);
+forward_ref_binop!(Add, add, f64, Complex);
+forward_ref_binop!(Sub, sub, f64, Complex);
+forward_ref_binop!(Mul, mul, f64, Complex);
+forward_ref_binop!(Div, div, f64, Complex);
+
+macro_rules! impl_assign_op {
+    ($op:ident, $method:ident, $bin:ident, $bin_method:ident) => {
+        impl ops::$op for Complex {
+            fn $method(&mut self, other: Complex) {
+                *self = ops::$bin::$bin_method(*self, other);
+            }
+        }
+
+        impl ops::$op<&Complex> for Complex {
+            fn $method(&mut self, other: &Complex) {
+                *self = ops::$bin::$bin_method(*self, *other);
+            }
+        }
+
+        impl ops::$op<f64> for Complex {
+            fn $method(&mut self, other: f64) {
+                *self = ops::$bin::$bin_method(*self, other);
+            }
+        }
+    };
+}
+
+impl_assign_op!(AddAssign, add_assign, Add, add);
+impl_assign_op!(SubAssign, sub_assign, Sub, sub);
+impl_assign_op!(MulAssign, mul_assign, Mul, mul);
+impl_assign_op!(DivAssign, div_assign, Div, div);
+
+impl iter::Sum for Complex {
+    fn sum<I: Iterator<Item = Complex>>(iter: I) -> Complex {
+        iter.fold(Complex::zero(), |acc, z| acc + z)
+    }
+}
+
+impl<'a> iter::Sum<&'a Complex> for Complex {
+    fn sum<I: Iterator<Item = &'a Complex>>(iter: I) -> Complex {
+        iter.copied().sum()
+    }
+}
+
+impl iter::Product for Complex {
+    fn product<I: Iterator<Item = Complex>>(iter: I) -> Complex {
+        iter.fold(Complex::one(), |acc, z| acc * z)
+    }
+}
+
+impl<'a> iter::Product<&'a Complex> for Complex {
+    fn product<I: Iterator<Item = &'a Complex>>(iter: I) -> Complex {
+        iter.copied().product()
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(re: f64) -> Self {
+        Complex { re, im: 0.0 }
+    }
+}
+
+impl From<(f64, f64)> for Complex {
+    fn from((re, im): (f64, f64)) -> Self {
+        Complex { re, im }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::f64::EPSILON;
 
     #[test]
     fn test_abs() {
         let c = Complex { re: 3.0, im: 4.0 };
-        assert!((c.abs() - 5.0) < EPSILON);
+        assert!((c.abs() - 5.0) < f64::EPSILON);
     }
 
     #[test]
     fn test_arg() {
         let c = Complex { re: 1.0, im: 1.0 };
-        assert!((c.arg() - f64::consts::FRAC_PI_4) < EPSILON);
+        assert!((c.arg() - f64::consts::FRAC_PI_4) < f64::EPSILON);
     }
 
     #[test]
     fn test_conj() {
         let c = Complex { re: 1.0, im: 1.0 };
         let conj = c.conj();
-        assert!((conj.re - 1.0) < EPSILON);
-        assert!((conj.im + 1.0) < EPSILON);
+        assert!((conj.re - 1.0) < f64::EPSILON);
+        assert!((conj.im + 1.0) < f64::EPSILON);
     }
 
     #[test]
     fn test_exp() {
         let c = Complex { re: 0.0, im: f64::consts::PI };
         let exp = c.exp();
-        assert!((exp.re + 1.0) < EPSILON);
-        assert!((exp.im) < EPSILON);
+        assert!((exp.re + 1.0) < f64::EPSILON);
+        assert!((exp.im) < f64::EPSILON);
     }
 
     #[test]
     fn test_ln() {
         let c = Complex { re: 1.0, im: 1.0 };
         let ln = c.ln();
-        assert!((ln.re - f64::consts::FRAC_1_SQRT_2) < EPSILON);
-        assert!((ln.im - f64::consts::FRAC_PI_4) < EPSILON);
+        assert!((ln.re - f64::consts::FRAC_1_SQRT_2) < f64::EPSILON);
+        assert!((ln.im - f64::consts::FRAC_PI_4) < f64::EPSILON);
     }
 
     #[test]
@@ -180,9 +631,297 @@ mod tests {
         let c = Complex { re: 1.0, im: 1.0 };
         let pow = c.pow(2.0);
 
-        assert!(true);
-        // assert!((pow.re - 0.0) < EPSILON );
-        // assert!((pow.im - 2.0) < EPSILON);
+        assert_eq!(pow, Complex::new(0.0, 2.0));
+        assert_eq!(c.pow(-2.0), Complex::new(0.0, -0.5));
+        assert_eq!(Complex::new(0.0, 1.0).pow(3.0), Complex::new(0.0, -1.0));
+        let sqrt = Complex::new(-4.0, 0.0).pow(0.5);
+        assert!(sqrt.re.abs() < 1e-15 && (sqrt.im - 2.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_abs_extremes() {
+        assert_eq!(Complex::new(3e300, 4e300).abs(), 5e300);
+        assert_eq!(Complex::new(3e-300, 4e-300).abs(), 5e-300);
+        assert_eq!(Complex::new(-0.0, 0.0).abs(), 0.0);
+        assert_eq!(Complex::new(f64::INFINITY, f64::NAN).abs(), f64::INFINITY);
+        assert!(Complex::new(1.0, f64::NAN).abs().is_nan());
+    }
+
+    #[test]
+    fn test_div_extremes() {
+        let one = Complex::new(1.0, 0.0);
+        let big = Complex::new(1e300, 1e300);
+        assert_eq!(big / big, one);
+        let tiny = Complex::new(1e-300, -1e-300);
+        assert_eq!(tiny / tiny, one);
+        assert_eq!(Complex::new(1e300, 0.0) / Complex::new(0.0, 1e300), Complex::new(0.0, -1.0));
+
+        let q = Complex::new(1.0, 0.0) / big;
+        assert_eq!(q, Complex::new(5e-301, -5e-301));
+        assert_eq!(2.0 / Complex::new(1e-200, 1e-200), Complex::new(1e200, -1e200));
+    }
+
+    #[test]
+    fn test_div_special_values() {
+        let z = Complex::new(1.0, 2.0);
+        let inf = Complex::new(f64::INFINITY, 0.0);
+        assert_eq!(z / inf, Complex::new(0.0, 0.0));
+        let q = Complex::new(f64::NAN, 1.0) / z;
+        assert!(q.re.is_nan() && q.im.is_nan());
+        let q = z / Complex::new(f64::NAN, 0.0);
+        assert!(q.re.is_nan() && q.im.is_nan());
+
+        let q = z / Complex::new(0.0, 0.0);
+        assert_eq!(q, Complex::new(f64::INFINITY, f64::INFINITY));
+        let q = Complex::new(-1.0, 0.0) / Complex::new(0.0, 0.0);
+        assert_eq!(q.re, f64::NEG_INFINITY);
+        assert!(q.im.is_nan());
+    }
+
+    #[test]
+    fn test_random_unit() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let c = Complex::random_unit(&mut rng);
+        assert!((c.abs() - 1.0).abs() < 1e-12);
+        assert_eq!(c, Complex::random_unit(&mut StdRng::seed_from_u64(42)));
+        assert_ne!(c, Complex::random_unit(&mut rng));
+    }
+
+    #[test]
+    fn test_display() {
+        let c = Complex::new(1.0, -0.25);
+        assert_eq!(c.to_string(), "1 - 0.25i");
+        assert_eq!(format!("{:.3}", c), "1.000 - 0.250i");
+        assert_eq!(format!("{:>12.1}", c), "  1.0 - 0.2i");
+        assert_eq!(format!("{:*<12.1}", c), "1.0 - 0.2i**");
+        assert_eq!(format!("{:.2e}", Complex::new(1500.0, 0.002)), "1.50e3 + 2.00e-3i");
+        assert_eq!(format!("{:E}", Complex::new(0.5, 1.0)), "5E-1 + 1E0i");
+        assert_eq!(Complex::new(1.0, -0.0).to_string(), "1 - 0i");
+    }
+
+    fn assert_near(a: Complex, b: Complex) {
+        assert!((a - b).abs() < 1e-12, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn test_sqrt_cbrt() {
+        assert_near(Complex::new(-4.0, 0.0).sqrt(), Complex::new(0.0, 2.0));
+        assert_near(Complex::new(0.0, 2.0).sqrt(), Complex::new(1.0, 1.0));
+        assert_near(Complex::new(-4.0, -0.0).sqrt(), Complex::new(0.0, -2.0));
+        let z = Complex::new(-3.0, 4.0);
+        assert_near(z.sqrt() * z.sqrt(), z);
+        assert!(z.sqrt().re >= 0.0);
+        assert_near(z.cbrt().powi(3), z);
+        assert_near(Complex::new(8.0, 0.0).cbrt(), Complex::new(2.0, 0.0));
+        assert_near(Complex::new(-8.0, 0.0).cbrt(), Complex::new(1.0, 3.0_f64.sqrt()));
+    }
+
+    #[test]
+    fn test_nth_roots() {
+        let z = Complex::new(8.0, 0.0);
+        let roots = z.nth_roots(3);
+        assert_eq!(roots.len(), 3);
+        assert_near(roots[0], Complex::new(2.0, 0.0));
+        for r in &roots {
+            assert_near(r.powi(3), z);
+        }
+        let sum = Complex::new(1.0, 0.0)
+            .nth_roots(5)
+            .into_iter()
+            .fold(Complex::new(0.0, 0.0), |acc, r| acc + r);
+        assert_near(sum, Complex::new(0.0, 0.0));
+        assert!(z.nth_roots(0).is_empty());
+    }
+
+    #[test]
+    fn test_powers_and_logs() {
+        let z = Complex::new(1.0, 1.0);
+        assert_near(z.powi(4), Complex::new(-4.0, 0.0));
+        assert_near(z.powi(-2), Complex::new(0.0, -0.5));
+        assert_near(z.powi(0), Complex::new(1.0, 0.0));
+
+        let i = Complex::new(0.0, 1.0);
+        assert_near(i.powc(i), Complex::new((-f64::consts::FRAC_PI_2).exp(), 0.0));
+        assert_near(z.powc(Complex::new(3.0, 0.0)), z.powi(3));
+        assert_near(Complex::new(0.0, 0.0).powc(z), Complex::new(0.0, 0.0));
+        assert_near(Complex::new(0.0, 0.0).powc(Complex::new(0.0, 0.0)), Complex::new(1.0, 0.0));
+        assert_eq!(Complex::new(0.0, 0.0).powc(Complex::new(-1.0, 0.0)), Complex::new(f64::INFINITY, 0.0));
+        assert_eq!(Complex::new(0.0, 0.0).powc(Complex::new(-1.0, 2.0)).re, f64::INFINITY);
+        assert_eq!(Complex::new(0.0, 0.0).powc(Complex::new(0.5, -3.0)), Complex::new(0.0, 0.0));
+        assert!(Complex::new(0.0, 0.0).powc(Complex::new(0.0, 1.0)).is_nan());
+
+        assert_near(Complex::new(100.0, 0.0).log(10.0), Complex::new(2.0, 0.0));
+        let expected = Complex::new(3.0, f64::consts::PI / 2.0_f64.ln());
+        assert_near(Complex::new(-8.0, 0.0).log(2.0), expected);
+    }
+
+    #[test]
+    fn test_trig_identities() {
+        let one = Complex::new(1.0, 0.0);
+        for z in [Complex::new(0.3, -0.7), Complex::new(-1.2, 0.4), Complex::new(2.0, 1.5)] {
+            assert_near(z.sin() * z.sin() + z.cos() * z.cos(), one);
+            assert_near(z.tan(), z.sin() / z.cos());
+            assert_near(z.cosh() * z.cosh() - z.sinh() * z.sinh(), one);
+            assert_near(z.tanh(), z.sinh() / z.cosh());
+            let iz = Complex::new(-z.im, z.re);
+            assert_near(iz.sin(), Complex::new(0.0, 1.0) * z.sinh());
+        }
+        assert_near(Complex::new(f64::consts::FRAC_PI_2, 0.0).sin(), one);
+    }
+
+    #[test]
+    fn test_tan_tanh_large_arguments() {
+        let i = Complex::new(0.0, 1.0);
+        assert_near(Complex::new(0.0, 400.0).tan(), i);
+        assert_near(Complex::new(1.0, -400.0).tan(), -i);
+        assert_near(Complex::new(400.0, 0.0).tanh(), Complex::new(1.0, 0.0));
+        assert_near(Complex::new(-400.0, 2.0).tanh(), Complex::new(-1.0, 0.0));
+        let z = Complex::new(21.5, 0.5);
+        assert_near(z.tanh(), z.sinh() / z.cosh());
+        assert!(Complex::new(1e3, 1e3).tanh().is_finite());
+    }
+
+    #[test]
+    fn test_inverse_functions() {
+        for z in [Complex::new(0.3, -0.7), Complex::new(-0.5, 0.4), Complex::new(0.9, 0.1)] {
+            assert_near(z.sin().asin(), z);
+            assert_near(z.cos().acos(), if z.re >= 0.0 { z } else { -z });
+            assert_near(z.tan().atan(), z);
+            assert_near(z.sinh().asinh(), z);
+            assert_near(z.tanh().atanh(), z);
+            assert_near(z.asin().sin(), z);
+            assert_near(z.acosh().cosh(), z);
+        }
+        for z in [Complex::new(0.0, 2.0), Complex::new(-3.0, 0.5), Complex::new(2.0, -0.1)] {
+            assert_near(z.asinh().sinh(), z);
+            assert_near(z.atanh().tanh(), z);
+        }
+        // Same side of the branch cut as C99's casin(2 + 0i).
+        let expected = Complex::new(f64::consts::FRAC_PI_2, (2.0 + 3.0_f64.sqrt()).ln());
+        assert_near(Complex::new(2.0, 0.0).asin(), expected);
+    }
+
+    #[test]
+    fn test_inverse_hyperbolic_extremes() {
+        let asinh = Complex::new(-1e8, 0.0).asinh();
+        assert!((asinh.re + 2e8_f64.ln()).abs() < 1e-12, "{}", asinh);
+        assert_eq!(asinh.im, 0.0);
+
+        for z in [Complex::new(1e-20, 0.0), Complex::new(-1e-20, 3e-21), Complex::new(0.0, 1e-20)] {
+            for w in [z.asinh(), z.atanh()] {
+                assert!((w - z).abs() <= 1e-15 * z.abs(), "{} != {}", w, z);
+            }
+        }
+        assert_eq!(Complex::new(1.0, 0.0).atanh().re, f64::INFINITY);
+        assert_near(Complex::new(0.5, 0.0).atanh(), Complex::new(0.5_f64.atanh(), 0.0));
+        assert_near(Complex::new(-0.5, 0.0).asinh(), Complex::new((-0.5_f64).asinh(), 0.0));
+    }
+
+    #[test]
+    fn test_default_and_from() {
+        assert_eq!(Complex::default(), Complex::new(0.0, 0.0));
+        assert_eq!(Complex::from(2.5), Complex::new(2.5, 0.0));
+        assert_eq!(Complex::from((1.0, -2.0)), Complex::new(1.0, -2.0));
+        let z: Complex = 3.0.into();
+        assert_eq!(z, Complex::new(3.0, 0.0));
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let zs = [Complex::new(1.0, 1.0), Complex::new(2.0, -1.0), Complex::new(0.0, 3.0)];
+        assert_eq!(zs.iter().sum::<Complex>(), Complex::new(3.0, 3.0));
+        assert_eq!(zs.into_iter().sum::<Complex>(), Complex::new(3.0, 3.0));
+        assert_eq!(zs.iter().product::<Complex>(), Complex::new(-3.0, 9.0));
+        assert_eq!(Vec::<Complex>::new().into_iter().product::<Complex>(), Complex::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn test_assign_ops() {
+        let mut z = Complex::new(1.0, 2.0);
+        z += Complex::new(1.0, 1.0);
+        assert_eq!(z, Complex::new(2.0, 3.0));
+        z -= 1.0;
+        assert_eq!(z, Complex::new(1.0, 3.0));
+        z *= &Complex::new(0.0, 1.0);
+        assert_eq!(z, Complex::new(-3.0, 1.0));
+        z /= 2.0;
+        assert_eq!(z, Complex::new(-1.5, 0.5));
+        z /= Complex::new(-1.5, 0.5);
+        assert_eq!(z, Complex::new(1.0, 0.0));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_mixed_and_ref_ops() {
+        let z = Complex::new(1.0, 2.0);
+        let w = Complex::new(3.0, -1.0);
+        assert_eq!(z + 1.0, Complex::new(2.0, 2.0));
+        assert_eq!(1.0 + z, Complex::new(2.0, 2.0));
+        assert_eq!(z - 1.0, Complex::new(0.0, 2.0));
+        assert_eq!(1.0 - z, Complex::new(0.0, -2.0));
+        assert_eq!(&z * &w, z * w);
+        assert_eq!(&z + w, z + w);
+        assert_eq!(z - &w, z - w);
+        assert_eq!(&z / &w, z / w);
+        assert_eq!(&z * 2.0, z * 2.0);
+        assert_eq!(2.0 * &z, z * 2.0);
+        assert_eq!(-&z, Complex::new(-1.0, -2.0));
+    }
+
+    #[test]
+    fn test_polar() {
+        let z = Complex::from_polar(2.0, f64::consts::FRAC_PI_2);
+        assert_near(z, Complex::new(0.0, 2.0));
+        let (r, theta) = Complex::new(-3.0, 3.0).to_polar();
+        assert!((r - 18.0_f64.sqrt()).abs() < 1e-15);
+        assert!((theta - 3.0 * f64::consts::FRAC_PI_4).abs() < 1e-15);
+        let w = Complex::new(1.5, -0.5);
+        let (r, theta) = w.to_polar();
+        assert_near(Complex::from_polar(r, theta), w);
+        assert_near(Complex::cis(f64::consts::PI), -Complex::one());
+        assert_eq!(Complex::cis(0.0), Complex::one());
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(Complex::i() * Complex::i(), -Complex::one());
+        assert_eq!(Complex::zero() + Complex::one(), Complex::new(1.0, 0.0));
+        assert_eq!(<Complex as Scalar>::zero(), Complex::zero());
+    }
+
+    #[test]
+    fn test_norm_sqr_normalize() {
+        let z = Complex::new(3.0, -4.0);
+        assert_eq!(z.norm_sqr(), 25.0);
+        assert_eq!(z.normalize(), Complex::new(0.6, -0.8));
+        assert_eq!(Complex::zero().normalize(), Complex::zero());
+        assert!((Complex::new(1e300, 1e300).normalize().abs() - 1.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_is_finite_is_nan() {
+        assert!(Complex::new(1.0, -2.0).is_finite());
+        assert!(!Complex::new(f64::INFINITY, 0.0).is_finite());
+        assert!(!Complex::new(0.0, f64::NAN).is_finite());
+        assert!(Complex::new(0.0, f64::NAN).is_nan());
+        assert!(!Complex::new(f64::INFINITY, 0.0).is_nan());
+    }
+
+    #[test]
+    fn test_roots_of_unity() {
+        let roots = Complex::roots_of_unity(6);
+        assert_eq!(roots.len(), 6);
+        assert_eq!(roots[0], Complex::one());
+        assert_near(roots[3], -Complex::one());
+        for r in &roots {
+            assert_near(r.powi(6), Complex::one());
+            assert!((r.abs() - 1.0).abs() < 1e-15);
+        }
+        assert_near(roots.iter().sum(), Complex::zero());
+        assert!(Complex::roots_of_unity(0).is_empty());
     }
 
     #[test]
@@ -190,8 +929,8 @@ mod tests {
         let c1 = Complex { re: 1.0, im: 1.0 };
         let c2 = Complex { re: 1.0, im: 1.0 };
         let sum = c1 + c2;
-        assert!((sum.re - 2.0) < EPSILON);
-        assert!((sum.im - 2.0) < EPSILON);
+        assert!((sum.re - 2.0) < f64::EPSILON);
+        assert!((sum.im - 2.0) < f64::EPSILON);
     }
 
     #[test]
@@ -199,16 +938,16 @@ mod tests {
         let c1 = Complex { re: 1.0, im: 1.0 };
         let c2 = Complex { re: 1.0, im: 1.0 };
         let sub = c1 - c2;
-        assert!((sub.re) < EPSILON);
-        assert!((sub.im) < EPSILON);
+        assert!((sub.re) < f64::EPSILON);
+        assert!((sub.im) < f64::EPSILON);
     }
 
     #[test]
     fn test_neg() {
         let c = Complex { re: 1.0, im: 1.0 };
         let neg = -c;
-        assert!((neg.re) < EPSILON);
-        assert!((neg.im) < EPSILON);
+        assert!((neg.re) < f64::EPSILON);
+        assert!((neg.im) < f64::EPSILON);
     }
 
     #[test]
@@ -216,16 +955,16 @@ mod tests {
         let c1 = Complex { re: 1.0, im: 1.0 };
         let c2 = Complex { re: 1.0, im: 1.0 };
         let mul = c1 * c2;
-        assert!((mul.re - 0.0) < EPSILON);
-        assert!((mul.im - 2.0) < EPSILON);
+        assert!((mul.re - 0.0) < f64::EPSILON);
+        assert!((mul.im - 2.0) < f64::EPSILON);
     }
 
     #[test]
     fn test_mul_f64() {
         let c = Complex { re: 1.0, im: 1.0 };
         let mul = c * 2.0;
-        assert!((mul.re - 2.0) < EPSILON);
-        assert!((mul.im - 2.0) < EPSILON);
+        assert!((mul.re - 2.0) < f64::EPSILON);
+        assert!((mul.im - 2.0) < f64::EPSILON);
     }
 
     #[test]
@@ -233,7 +972,7 @@ mod tests {
         let c1 = Complex { re: 1.0, im: 1.0 };
         let c2 = Complex { re: 1.0, im: 1.0 };
         let div = c1 / c2;
-        assert!((div.re - 1.0) < EPSILON);
-        assert!((div.im) < EPSILON);
+        assert!((div.re - 1.0) < f64::EPSILON);
+        assert!((div.im) < f64::EPSILON);
     }
 }
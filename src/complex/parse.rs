@@ -0,0 +1,428 @@
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+use super::Complex;
+
+/// Error returned when parsing a [`Complex`] fails.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ParseComplexError {
+    kind: ParseComplexErrorKind,
+    position: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParseComplexErrorKind {
+    /// The input is empty or only whitespace.
+    Empty,
+    /// A number or imaginary unit was expected.
+    ExpectedNumber,
+    /// A character that doesn't fit the grammar at this point.
+    UnexpectedChar(char),
+    /// The input stops in the middle of a value.
+    UnexpectedEnd,
+    /// Two real or two imaginary parts, e.g. `1 + 2`.
+    DuplicatePart,
+}
+
+impl ParseComplexError {
+    pub fn kind(&self) -> ParseComplexErrorKind {
+        self.kind
+    }
+
+    /// Byte offset into the input at which parsing failed.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseComplexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid complex number at position {}: ", self.position)?;
+        match self.kind {
+            ParseComplexErrorKind::Empty => write!(f, "empty input."),
+            ParseComplexErrorKind::ExpectedNumber => write!(f, "expected a number."),
+            ParseComplexErrorKind::UnexpectedChar(c) => write!(f, "unexpected character {:?}.", c),
+            ParseComplexErrorKind::UnexpectedEnd => write!(f, "unexpected end of input."),
+            ParseComplexErrorKind::DuplicatePart => {
+                write!(f, "real or imaginary part given twice.")
+            }
+        }
+    }
+}
+
+impl error::Error for ParseComplexError {}
+
+/// Parses the forms
+///
+/// - rectangular: `3`, `-4i`, `3 - 4i`, `3-4j`, `-4i + 3`, `i`, `1e-3+2.5E2i`
+/// - polar: `2∠1.5708` (radians) or `2∠-90°` (degrees)
+/// - exponential: `2*exp(i1.5708)` or `2*exp(-1.5708j)`
+///
+/// Whitespace is allowed between tokens, so the output of `Display` parses
+/// back, including `inf` and `NaN` parts.
+impl FromStr for Complex {
+    type Err = ParseComplexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { s, pos: 0 };
+        parser.skip_ws();
+        if parser.peek().is_none() {
+            return Err(parser.error(ParseComplexErrorKind::Empty));
+        }
+        let z = parser.complex()?;
+        parser.skip_ws();
+        match parser.peek() {
+            None => Ok(z),
+            Some(c) => Err(parser.error(ParseComplexErrorKind::UnexpectedChar(c))),
+        }
+    }
+}
+
+struct Parser<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+/// A signed number with or without an imaginary unit.
+struct Term {
+    value: f64,
+    imaginary: bool,
+    start: usize,
+}
+
+impl Parser<'_> {
+    fn complex(&mut self) -> Result<Complex, ParseComplexError> {
+        let first = self.term()?;
+        self.skip_ws();
+        if !first.imaginary {
+            match self.peek() {
+                Some('∠') => {
+                    self.bump();
+                    return self.polar(first.value);
+                }
+                Some('*') => {
+                    self.bump();
+                    return self.exponential(first.value);
+                }
+                _ => {}
+            }
+        }
+
+        // The operator may be followed by the sign of the part, as in `1 + -0i`.
+        let start = self.pos;
+        let second = match self.peek() {
+            Some('+') | Some('-') => {
+                let sign = self.sign();
+                let term = self.term()?;
+                Term {
+                    value: sign * term.value,
+                    start,
+                    ..term
+                }
+            }
+            _ => return Ok(first.into_complex()),
+        };
+        match (first.imaginary, second.imaginary) {
+            (false, true) => Ok(Complex::new(first.value, second.value)),
+            (true, false) => Ok(Complex::new(second.value, first.value)),
+            _ => Err(ParseComplexError {
+                kind: ParseComplexErrorKind::DuplicatePart,
+                position: second.start,
+            }),
+        }
+    }
+
+    /// `θ` or `θ°` after `r∠`.
+    fn polar(&mut self, r: f64) -> Result<Complex, ParseComplexError> {
+        self.skip_ws();
+        let mut theta = self.sign() * self.number()?;
+        if self.peek() == Some('°') {
+            self.bump();
+            theta = theta.to_radians();
+        }
+        Ok(Complex::from_polar(r, theta))
+    }
+
+    /// `exp(iθ)` or `exp(θi)` after `r*`.
+    fn exponential(&mut self, r: f64) -> Result<Complex, ParseComplexError> {
+        self.skip_ws();
+        self.expect_str("exp")?;
+        self.skip_ws();
+        self.expect_str("(")?;
+        self.skip_ws();
+        let theta = self.term()?;
+        if !theta.imaginary {
+            return Err(self.unexpected());
+        }
+        self.skip_ws();
+        self.expect_str(")")?;
+        Ok(Complex::from_polar(r, theta.value))
+    }
+
+    /// `[sign] number [unit]`, `[sign] unit [number]` or `[sign] unit`.
+    fn term(&mut self) -> Result<Term, ParseComplexError> {
+        self.skip_ws();
+        let start = self.pos;
+        let sign = self.sign();
+
+        // `inf` starts with the imaginary unit, so look for a number first.
+        if !self.starts_number() && self.unit() {
+            let value = if self.starts_number() {
+                self.number()?
+            } else {
+                1.0
+            };
+            return Ok(Term {
+                value: sign * value,
+                imaginary: true,
+                start,
+            });
+        }
+        let value = sign * self.number()?;
+        let imaginary = self.unit();
+        Ok(Term {
+            value,
+            imaginary,
+            start,
+        })
+    }
+
+    /// Consumes an optional `+` or `-` and the whitespace after it.
+    fn sign(&mut self) -> f64 {
+        let sign = match self.peek() {
+            Some('+') => 1.0,
+            Some('-') => -1.0,
+            _ => return 1.0,
+        };
+        self.bump();
+        self.skip_ws();
+        sign
+    }
+
+    /// Unsigned decimal or scientific number, `inf`, `infinity` or `NaN`.
+    fn number(&mut self) -> Result<f64, ParseComplexError> {
+        let start = self.pos;
+        let rest = &self.s[start..];
+        let len = ["infinity", "inf", "nan"]
+            .iter()
+            .find(|word| {
+                rest.get(..word.len())
+                    .is_some_and(|head| head.eq_ignore_ascii_case(word))
+            })
+            .map(|word| word.len())
+            .unwrap_or_else(|| decimal_len(rest));
+        if len == 0 {
+            return Err(match self.peek() {
+                None => self.error(ParseComplexErrorKind::UnexpectedEnd),
+                Some(_) => self.error(ParseComplexErrorKind::ExpectedNumber),
+            });
+        }
+        self.pos += len;
+        rest[..len].parse().map_err(|_| ParseComplexError {
+            kind: ParseComplexErrorKind::ExpectedNumber,
+            position: start,
+        })
+    }
+
+    fn starts_number(&self) -> bool {
+        let rest = &self.s[self.pos..];
+        decimal_len(rest) > 0
+            || ["inf", "nan"].iter().any(|word| {
+                rest.get(..3)
+                    .is_some_and(|head| head.eq_ignore_ascii_case(word))
+            })
+    }
+
+    /// Consumes an imaginary unit `i` or `j` if one follows.
+    fn unit(&mut self) -> bool {
+        match self.peek() {
+            Some('i') | Some('j') => {
+                self.bump();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn expect_str(&mut self, expected: &str) -> Result<(), ParseComplexError> {
+        for c in expected.chars() {
+            if self.peek() != Some(c) {
+                return Err(self.unexpected());
+            }
+            self.bump();
+        }
+        Ok(())
+    }
+
+    fn skip_ws(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.s[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn unexpected(&self) -> ParseComplexError {
+        match self.peek() {
+            Some(c) => self.error(ParseComplexErrorKind::UnexpectedChar(c)),
+            None => self.error(ParseComplexErrorKind::UnexpectedEnd),
+        }
+    }
+
+    fn error(&self, kind: ParseComplexErrorKind) -> ParseComplexError {
+        ParseComplexError {
+            kind,
+            position: self.pos,
+        }
+    }
+}
+
+impl Term {
+    fn into_complex(self) -> Complex {
+        if self.imaginary {
+            Complex::new(0.0, self.value)
+        } else {
+            Complex::new(self.value, 0.0)
+        }
+    }
+}
+
+/// Length of the longest prefix of the form `digits[.digits][e[sign]digits]`
+/// with at least one mantissa digit, or 0.
+fn decimal_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let digits = |from: usize| {
+        bytes[from..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+
+    let mut len = digits(0);
+    let mut mantissa = len;
+    if bytes.get(len) == Some(&b'.') {
+        let frac = digits(len + 1);
+        mantissa += frac;
+        len += 1 + frac;
+    }
+    if mantissa == 0 {
+        return 0;
+    }
+    if matches!(bytes.get(len), Some(b'e') | Some(b'E')) {
+        let sign = usize::from(matches!(bytes.get(len + 1), Some(b'+') | Some(b'-')));
+        let exp = digits(len + 1 + sign);
+        if exp > 0 {
+            len += 1 + sign + exp;
+        }
+    }
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Complex {
+        s.parse().unwrap()
+    }
+
+    fn error(s: &str) -> (ParseComplexErrorKind, usize) {
+        let e = s.parse::<Complex>().unwrap_err();
+        (e.kind(), e.position())
+    }
+
+    #[test]
+    fn test_parse_rectangular() {
+        assert_eq!(parse("3"), Complex::new(3.0, 0.0));
+        assert_eq!(parse("-4i"), Complex::new(0.0, -4.0));
+        assert_eq!(parse("3+4i"), Complex::new(3.0, 4.0));
+        assert_eq!(parse("3 - 4i"), Complex::new(3.0, -4.0));
+        assert_eq!(parse(" -1.5-2.25j "), Complex::new(-1.5, -2.25));
+        assert_eq!(parse("2i + 1"), Complex::new(1.0, 2.0));
+        assert_eq!(parse("i"), Complex::new(0.0, 1.0));
+        assert_eq!(parse("1 - i"), Complex::new(1.0, -1.0));
+        assert_eq!(parse("-j2"), Complex::new(0.0, -2.0));
+        assert_eq!(parse(".5"), Complex::new(0.5, 0.0));
+    }
+
+    #[test]
+    fn test_parse_scientific() {
+        assert_eq!(parse("1e3"), Complex::new(1000.0, 0.0));
+        assert_eq!(parse("1.5E-3 + 2e+2i"), Complex::new(1.5e-3, 200.0));
+        assert_eq!(parse("-2.5e1j"), Complex::new(0.0, -25.0));
+    }
+
+    #[test]
+    fn test_parse_polar() {
+        let z = parse("2∠90°");
+        assert!(z.re.abs() < 1e-15 && (z.im - 2.0).abs() < 1e-15);
+        let z = parse("2 ∠ 3.141592653589793");
+        assert!((z.re + 2.0).abs() < 1e-15 && z.im.abs() < 1e-15);
+        let z = parse("2*exp(i1.5707963267948966)");
+        assert!(z.re.abs() < 1e-15 && (z.im - 2.0).abs() < 1e-15);
+        assert_eq!(parse("3 * exp( 0j )"), Complex::new(3.0, 0.0));
+
+        let z = parse("2∠-90°");
+        assert!(z.re.abs() < 1e-15 && (z.im + 2.0).abs() < 1e-15);
+        assert_eq!(parse("2 ∠ +0.5"), Complex::from_polar(2.0, 0.5));
+        assert_eq!(parse("2*exp(-1.5j)"), Complex::from_polar(2.0, -1.5));
+        assert_eq!(parse("2*exp(-i1.5)"), Complex::from_polar(2.0, -1.5));
+    }
+
+    #[test]
+    fn test_parse_display_round_trip() {
+        for z in [
+            Complex::new(3.0, -4.0),
+            Complex::new(-0.125, 1e-10),
+            Complex::new(1e300, -2.5e-300),
+            Complex::new(f64::INFINITY, f64::NEG_INFINITY),
+        ] {
+            assert_eq!(parse(&z.to_string()), z);
+            assert_eq!(parse(&format!("{:e}", z)), z);
+        }
+        let z = parse(&Complex::new(f64::NAN, 1.0).to_string());
+        assert!(z.re.is_nan() && z.im == 1.0);
+        for s in [Complex::new(1.0, -0.0).to_string(), "1 + -0i".to_string()] {
+            let z = parse(&s);
+            assert!(z.re == 1.0 && z.im == 0.0 && z.im.is_sign_negative());
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(error(""), (ParseComplexErrorKind::Empty, 0));
+        assert_eq!(error("   "), (ParseComplexErrorKind::Empty, 3));
+        assert_eq!(error("3 +"), (ParseComplexErrorKind::UnexpectedEnd, 3));
+        assert_eq!(error("3 + x"), (ParseComplexErrorKind::ExpectedNumber, 4));
+        assert_eq!(
+            error("3 4i"),
+            (ParseComplexErrorKind::UnexpectedChar('4'), 2)
+        );
+        assert_eq!(error("1 + 2"), (ParseComplexErrorKind::DuplicatePart, 2));
+        assert_eq!(error("3i - 2j"), (ParseComplexErrorKind::DuplicatePart, 3));
+        assert_eq!(error("2∠"), (ParseComplexErrorKind::UnexpectedEnd, 4));
+        assert_eq!(
+            error("2*exp(1.0)"),
+            (ParseComplexErrorKind::UnexpectedChar(')'), 9)
+        );
+        assert_eq!(
+            error("2*ex(i)"),
+            (ParseComplexErrorKind::UnexpectedChar('('), 4)
+        );
+
+        let e = "1 + 2".parse::<Complex>().unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Invalid complex number at position 2: real or imaginary part given twice."
+        );
+    }
+}
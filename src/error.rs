@@ -0,0 +1,107 @@
+use std::error;
+use std::fmt;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Error {
+    ShapeMismatch {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+    NotSquare {
+        rows: usize,
+        cols: usize,
+    },
+    Underdetermined {
+        rows: usize,
+        cols: usize,
+    },
+    LengthMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    Singular,
+    DivisionByZero,
+    IndexOutOfBounds {
+        index: usize,
+        bound: usize,
+    },
+    Empty,
+    NotPositiveDefinite {
+        pivot: usize,
+    },
+    NotSymmetric,
+    NoConvergence,
+    NotDiagonalizable,
+    NonRealResult,
+    InvalidArgument {
+        name: &'static str,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::ShapeMismatch { left, right } => write!(
+                f,
+                "Matrix shapes {}x{} and {}x{} are not compatible.",
+                left.0, left.1, right.0, right.1
+            ),
+            Error::NotSquare { rows, cols } => {
+                write!(f, "Matrix must be square, got {}x{}.", rows, cols)
+            }
+            Error::Underdetermined { rows, cols } => write!(
+                f,
+                "Matrix must have at least as many rows as columns, got {}x{}.",
+                rows, cols
+            ),
+            Error::LengthMismatch { expected, actual } => write!(
+                f,
+                "Data length must match matrix size: expected {}, got {}.",
+                expected, actual
+            ),
+            Error::Singular => write!(f, "Matrix is singular."),
+            Error::DivisionByZero => write!(f, "Cannot divide by zero."),
+            Error::IndexOutOfBounds { index, bound } => {
+                write!(f, "Index {} out of bounds for length {}.", index, bound)
+            }
+            Error::Empty => write!(f, "Matrix must not be empty."),
+            Error::NotPositiveDefinite { pivot } => write!(
+                f,
+                "Matrix is not positive definite: pivot {} is not positive.",
+                pivot
+            ),
+            Error::NotSymmetric => write!(f, "Matrix must be symmetric."),
+            Error::NoConvergence => write!(f, "Iterative algorithm did not converge."),
+            Error::NotDiagonalizable => write!(f, "Matrix is not diagonalizable."),
+            Error::NonRealResult => write!(f, "Result is not a real matrix."),
+            Error::InvalidArgument { name } => write!(f, "Invalid value for `{}`.", name),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let e = Error::ShapeMismatch {
+            left: (2, 3),
+            right: (2, 2),
+        };
+        assert_eq!(
+            e.to_string(),
+            "Matrix shapes 2x3 and 2x2 are not compatible."
+        );
+        assert_eq!(
+            Error::NotSquare { rows: 2, cols: 3 }.to_string(),
+            "Matrix must be square, got 2x3."
+        );
+        assert_eq!(
+            Error::IndexOutOfBounds { index: 4, bound: 3 }.to_string(),
+            "Index 4 out of bounds for length 3."
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use std::fmt;
+
+/// Pads `s` to at least `width` characters with the formatter's fill
+/// character and alignment. Numbers are right-aligned unless the format
+/// string asks otherwise, matching the standard library.
+pub(crate) fn pad(f: &fmt::Formatter, s: &str, width: usize) -> String {
+    let len = s.chars().count();
+    if len >= width {
+        return s.to_string();
+    }
+    let fill = f.fill().to_string();
+    let gap = width - len;
+    let (left, right) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, gap),
+        Some(fmt::Alignment::Center) => (gap / 2, gap - gap / 2),
+        _ => (gap, 0),
+    };
+    format!("{}{}{}", fill.repeat(left), s, fill.repeat(right))
+}
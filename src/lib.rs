@@ -1,2 +1,7 @@
-pub mod matrix;
 pub mod complex;
+pub mod error;
+mod format;
+pub mod matrix;
+pub mod scalar;
+
+pub use error::Error;
@@ -1,31 +1,73 @@
+use std::cmp::Ordering;
 use std::f64;
-use std::fmt;
 use std::ops;
 
+use crate::complex::Complex;
+use crate::scalar::Scalar;
+use crate::Error;
+
+mod cholesky;
+mod construct;
+mod display;
+mod eigen;
+mod elementwise;
+mod gemm;
+mod iter;
+mod lu;
+mod matfun;
+mod norm;
+mod qr;
+mod random;
+mod stats;
+mod svd;
+mod view;
+
+pub use cholesky::{Cholesky, Ldlt};
+pub use display::{Style, Styled};
+pub use eigen::{Eigen, SymmetricEigen};
+pub use elementwise::broadcast_shape;
+pub use lu::Lu;
+pub use norm::DEFAULT_APPROX_TOL;
+pub use qr::{Lstsq, PivotedQr, Qr};
+pub use stats::Axis;
+pub use svd::Svd;
+pub use view::{MatView, MatViewMut};
+
 #[derive(Clone, PartialEq, Debug)]
-pub struct Mat {
-    data: Vec<f64>,
+pub struct Mat<T: Scalar = f64> {
+    data: Vec<T>,
     rows: usize,
     cols: usize,
 }
 
-impl Mat {
+impl<T: Scalar> Mat<T> {
+    /// Zero matrix of the given shape. Either dimension may be 0, which gives
+    /// an empty matrix like [`Mat::from_vec`] and [`Mat::sub_matrix`] do.
     pub fn new(rows: usize, cols: usize) -> Self {
-        assert!(rows != 0 && cols != 0, "Can't create an empty matrix!");
-        let data = vec![0.0; rows * cols];
-        Self { data, rows, cols }
+        Self::filled(rows, cols, T::zero())
     }
 
-    pub fn from_vec(rows: usize, cols: usize, data: Vec<f64>) -> Self {
-        assert_eq!(
-            rows * cols,
-            data.len(),
-            "Data length must match matrix size."
-        );
-        Self { data, rows, cols }
+    /// Counterpart of [`Mat::new`] for symmetry with the other `try_*`
+    /// constructors. Every shape is valid, so this never fails.
+    pub fn try_new(rows: usize, cols: usize) -> Result<Self, Error> {
+        Ok(Self::new(rows, cols))
+    }
+
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        Self::try_from_vec(rows, cols, data).unwrap_or_else(|e| panic!("{}", e))
     }
 
-    pub fn filled(rows: usize, cols: usize, value: f64) -> Self {
+    pub fn try_from_vec(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, Error> {
+        if rows * cols != data.len() {
+            return Err(Error::LengthMismatch {
+                expected: rows * cols,
+                actual: data.len(),
+            });
+        }
+        Ok(Self { data, rows, cols })
+    }
+
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self {
         let data = vec![value; rows * cols];
         Self { data, rows, cols }
     }
@@ -35,13 +77,14 @@ impl Mat {
     }
 
     pub fn ones(rows: usize, cols: usize) -> Self {
-        Self::filled(rows, cols, 1.0)
+        Self::filled(rows, cols, T::one())
     }
 
+    /// Identity matrix; `eye(0)` is the empty `0x0` matrix.
     pub fn eye(size: usize) -> Self {
         let mut m = Self::zeros(size, size);
         for i in 0..size {
-            m[(i, i)] = 1.0;
+            m[(i, i)] = T::one();
         }
         m
     }
@@ -50,8 +93,20 @@ impl Mat {
         (self.rows, self.cols)
     }
 
+    pub fn get(&self, row: usize, col: usize) -> Result<&T, Error> {
+        self.check_row(row)?;
+        self.check_col(col)?;
+        Ok(&self.data[row * self.cols + col])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Result<&mut T, Error> {
+        self.check_row(row)?;
+        self.check_col(col)?;
+        Ok(&mut self.data[row * self.cols + col])
+    }
+
     pub fn transpose(&self) -> Self {
-        let mut m = Self::zeros(self.cols, self.rows);
+        let mut m = Self::filled(self.cols, self.rows, T::zero());
         for i in 0..self.rows {
             for j in 0..self.cols {
                 m[(j, i)] = self[(i, j)];
@@ -60,124 +115,242 @@ impl Mat {
         m
     }
 
-    pub fn trace(&self) -> f64 {
-        assert_eq!(self.rows, self.cols, "Matrix must be square.");
-        (0..self.rows).map(|i| self[(i, i)]).sum()
+    pub fn trace(&self) -> T {
+        self.try_trace().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_trace(&self) -> Result<T, Error> {
+        self.check_square()?;
+        Ok((0..self.rows).fold(T::zero(), |acc, i| acc + self[(i, i)]))
     }
 
     pub fn dot(&self, other: &Self) -> Self {
-        assert_eq!(self.cols, other.rows, "Matrix dimensions not compatible.");
-        self * other
+        self.try_dot(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_dot(&self, other: &Self) -> Result<Self, Error> {
+        if self.cols != other.rows {
+            return Err(Error::ShapeMismatch {
+                left: self.shape(),
+                right: other.shape(),
+            });
+        }
+
+        Ok(gemm::gemm(self, other))
+    }
+
+    pub fn try_add(&self, other: &Self) -> Result<Self, Error> {
+        self.try_zip_map(other, |a, b| a + b)
+    }
+
+    pub fn try_sub(&self, other: &Self) -> Result<Self, Error> {
+        self.try_zip_map(other, |a, b| a - b)
+    }
+
+    /// Divides every entry by `scalar`, or fails for a zero divisor instead
+    /// of panicking like `m / scalar`.
+    pub fn try_div(&self, scalar: T) -> Result<Self, Error> {
+        if scalar == T::zero() {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(self.map(|val| val / scalar))
     }
 
+    /// Copy without row `row` and column `col`. Removing the only row or
+    /// column leaves an empty matrix.
     pub fn sub_matrix(&self, row: usize, col: usize) -> Self {
-        assert!(row < self.rows, "Row index out of bounds.");
-        assert!(col < self.cols, "Column index out of bounds.");
-        assert!(
-            self.cols > 1 && self.rows > 1,
-            "Matrix must be at least 2x2."
-        );
+        self.try_sub_matrix(row, col)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
 
-        let mut m = Self::zeros(self.rows - 1, self.cols - 1);
+    pub fn try_sub_matrix(&self, row: usize, col: usize) -> Result<Self, Error> {
+        self.check_row(row)?;
+        self.check_col(col)?;
+
+        let mut m = Self::filled(self.rows - 1, self.cols - 1, T::zero());
         for (i, r) in (0..self.rows).filter(|&r| r != row).enumerate() {
             for (j, c) in (0..self.cols).filter(|&c| c != col).enumerate() {
                 m[(i, j)] = self[(r, c)];
             }
         }
-        m
+        Ok(m)
     }
 
     pub fn get_row(&self, row: usize) -> Self {
-        assert!(row < self.rows, "Row index out of bounds.");
+        self.try_get_row(row).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_get_row(&self, row: usize) -> Result<Self, Error> {
+        self.check_row(row)?;
         let start = row * self.cols;
 
-        Mat::from_vec(1, self.cols, self.data[start..start + self.cols].to_vec())
+        Self::try_from_vec(1, self.cols, self.data[start..start + self.cols].to_vec())
     }
 
     pub fn get_col(&self, col: usize) -> Self {
-        assert!(col < self.cols, "Column index out of bounds.");
+        self.try_get_col(col).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_get_col(&self, col: usize) -> Result<Self, Error> {
+        self.check_col(col)?;
         let mut v = Vec::with_capacity(self.rows);
         for i in 0..self.rows {
             v.push(self[(i, col)]);
         }
-        Mat::from_vec(self.rows, 1, v)
+        Self::try_from_vec(self.rows, 1, v)
     }
 
-    pub fn det(&self) -> f64 {
-        assert_eq!(self.rows, self.cols, "Matrix must be square!");
-
-        match self.rows {
-            1 => self[(0, 0)],
-            2 => self[(0, 0)] * self[(1, 1)] - self[(0, 1)] * self[(1, 0)],
-            _ => (0..self.cols)
-                .map(|i| {
-                    let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
-                    sign * self[(0, i)] * self.sub_matrix(0, i).det()
-                })
-                .sum(),
-        }
+    pub fn pow(&self, n: u32) -> Self {
+        self.try_pow(n).unwrap_or_else(|e| panic!("{}", e))
     }
 
-    pub fn pow(&self, n: u32) -> Self {
-        assert_eq!(self.rows, self.cols, "Matrix must be square!");
+    pub fn try_pow(&self, n: u32) -> Result<Self, Error> {
+        self.check_square()?;
 
-        match n {
+        Ok(match n {
             0 => Self::eye(self.rows),
             1 => self.clone(),
             _ => {
-                let half = self.pow(n / 2);
+                let half = self.try_pow(n / 2)?;
                 let result = &half * &half;
-                if n % 2 == 0 {
+                if n.is_multiple_of(2) {
                     result
                 } else {
                     &result * self
                 }
             }
+        })
+    }
+
+    pub(crate) fn swap_rows(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        for j in 0..self.cols {
+            self.data.swap(a * self.cols + j, b * self.cols + j);
+        }
+    }
+
+    pub(crate) fn check_square(&self) -> Result<(), Error> {
+        if self.rows != self.cols {
+            return Err(Error::NotSquare {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+        Ok(())
+    }
+
+    fn check_row(&self, row: usize) -> Result<(), Error> {
+        if row >= self.rows {
+            return Err(Error::IndexOutOfBounds {
+                index: row,
+                bound: self.rows,
+            });
+        }
+        Ok(())
+    }
+
+    fn check_col(&self, col: usize) -> Result<(), Error> {
+        if col >= self.cols {
+            return Err(Error::IndexOutOfBounds {
+                index: col,
+                bound: self.cols,
+            });
         }
+        Ok(())
+    }
+}
+
+impl Mat {
+    pub fn det(&self) -> f64 {
+        self.try_det().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_det(&self) -> Result<f64, Error> {
+        self.check_square()?;
+
+        Ok(match self.rows {
+            1 => self[(0, 0)],
+            2 => self[(0, 0)] * self[(1, 1)] - self[(0, 1)] * self[(1, 0)],
+            3 => {
+                self[(0, 0)] * (self[(1, 1)] * self[(2, 2)] - self[(1, 2)] * self[(2, 1)])
+                    - self[(0, 1)] * (self[(1, 0)] * self[(2, 2)] - self[(1, 2)] * self[(2, 0)])
+                    + self[(0, 2)] * (self[(1, 0)] * self[(2, 1)] - self[(1, 1)] * self[(2, 0)])
+            }
+            _ => self.try_lu()?.det(),
+        })
     }
 
     pub fn cofactor(&self) -> Self {
-        assert_eq!(self.rows, self.cols, "Matrix must be square!");
+        self.try_cofactor().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// The cofactor matrix of a `1 x 1` matrix is `[1]`, the determinant of
+    /// its empty minor.
+    pub fn try_cofactor(&self) -> Result<Self, Error> {
+        self.check_square()?;
+        if self.rows == 1 {
+            return Ok(Self::ones(1, 1));
+        }
 
         let mut adj = Self::zeros(self.rows, self.cols);
         for i in 0..self.rows {
             for j in 0..self.cols {
-                let sub_mat = self.sub_matrix(i, j);
+                let sub_mat = self.try_sub_matrix(i, j)?;
                 let sign = if (i + j) % 2 == 0 { 1.0 } else { -1.0 };
-                adj[(i, j)] = sign * sub_mat.det();
+                adj[(i, j)] = sign * sub_mat.try_det()?;
             }
         }
-        adj
+        Ok(adj)
     }
 
     pub fn adjugate(&self) -> Self {
-        self.cofactor().transpose()
+        self.try_adjugate().unwrap_or_else(|e| panic!("{}", e))
     }
 
+    pub fn try_adjugate(&self) -> Result<Self, Error> {
+        Ok(self.try_cofactor()?.transpose())
+    }
+
+    /// `None` for a singular or non-square matrix; [`Mat::try_inverse`]
+    /// tells the two apart.
     pub fn inverse(&self) -> Option<Self> {
-        let det = self.det();
-        if det == 0.0 {
-            None
-        } else {
-            Some(&self.adjugate() / det)
-        }
+        self.try_inverse().ok()
     }
-}
 
-impl fmt::Display for Mat {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for i in 0..self.rows {
-            for j in 0..self.cols {
-                write!(f, "{:.2} ", self[(i, j)])?;
+    pub fn try_inverse(&self) -> Result<Self, Error> {
+        self.try_lu()?.inverse()
+    }
+
+    pub fn solve(&self, b: &Self) -> Result<Self, Error> {
+        if b.rows != self.rows {
+            return Err(Error::ShapeMismatch {
+                left: self.shape(),
+                right: b.shape(),
+            });
+        }
+
+        match self.rows.cmp(&self.cols) {
+            Ordering::Equal => self.lu().solve(b),
+            Ordering::Greater => self.qr().solve(b),
+            Ordering::Less => {
+                let qr = self.transpose().qr();
+                if qr.is_singular() {
+                    return Err(Error::Singular);
+                }
+                let y = qr.solve_rt(b);
+                let mut x = Self::zeros(self.cols, b.cols);
+                x.data[..y.data.len()].copy_from_slice(&y.data);
+                qr.apply_q(&mut x);
+                Ok(x)
             }
-            writeln!(f)?;
         }
-        Ok(())
     }
 }
 
-impl ops::Index<(usize, usize)> for Mat {
-    type Output = f64;
+impl<T: Scalar> ops::Index<(usize, usize)> for Mat<T> {
+    type Output = T;
 
     fn index(&self, index: (usize, usize)) -> &Self::Output {
         let (i, j) = index;
@@ -185,135 +358,218 @@ impl ops::Index<(usize, usize)> for Mat {
     }
 }
 
-impl ops::IndexMut<(usize, usize)> for Mat {
+impl<T: Scalar> ops::IndexMut<(usize, usize)> for Mat<T> {
     fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
         let (i, j) = index;
         &mut self.data[i * self.cols + j]
     }
 }
 
-impl ops::Add for &Mat {
-    type Output = Mat;
+macro_rules! impl_elementwise_op {
+    ($op:ident, $method:ident, $assign:ident, $assign_method:ident, $try_method:ident) => {
+        impl<T: Scalar> ops::$op for &Mat<T> {
+            type Output = Mat<T>;
 
-    fn add(self, other: &Mat) -> Mat {
-        assert_eq!(
-            self.rows, other.rows,
-            "Matrices must have the same dimensions"
-        );
-        assert_eq!(
-            self.cols, other.cols,
-            "Matrices must have the same dimensions"
-        );
+            fn $method(self, other: &Mat<T>) -> Mat<T> {
+                self.$try_method(other).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
 
-        let mut result = Mat::zeros(self.rows, self.cols);
-        for i in 0..self.rows {
-            for j in 0..self.cols {
-                result[(i, j)] = self[(i, j)] + other[(i, j)];
+        impl<T: Scalar> ops::$op<&Mat<T>> for Mat<T> {
+            type Output = Mat<T>;
+
+            fn $method(mut self, other: &Mat<T>) -> Mat<T> {
+                if !self.broadcasts_from(other.shape()) {
+                    return ops::$op::$method(&self, other);
+                }
+                ops::$assign::$assign_method(&mut self, other);
+                self
             }
         }
-        result
-    }
-}
 
-impl ops::Sub for &Mat {
-    type Output = Mat;
+        impl<T: Scalar> ops::$op<Mat<T>> for &Mat<T> {
+            type Output = Mat<T>;
 
-    fn sub(self, other: &Mat) -> Mat {
-        assert_eq!(
-            self.rows, other.rows,
-            "Matrices must have the same dimensions"
-        );
-        assert_eq!(
-            self.cols, other.cols,
-            "Matrices must have the same dimensions"
-        );
+            fn $method(self, mut other: Mat<T>) -> Mat<T> {
+                if !other.broadcasts_from(self.shape()) {
+                    return ops::$op::$method(self, &other);
+                }
+                other
+                    .zip_assign(self, |b, a| ops::$op::$method(a, b))
+                    .unwrap_or_else(|e| panic!("{}", e));
+                other
+            }
+        }
 
-        let mut result = Mat::zeros(self.rows, self.cols);
-        for i in 0..self.rows {
-            for j in 0..self.cols {
-                result[(i, j)] = self[(i, j)] - other[(i, j)];
+        impl<T: Scalar> ops::$op for Mat<T> {
+            type Output = Mat<T>;
+
+            fn $method(self, other: Mat<T>) -> Mat<T> {
+                ops::$op::$method(self, &other)
+            }
+        }
+
+        impl<T: Scalar> ops::$assign<&Mat<T>> for Mat<T> {
+            fn $assign_method(&mut self, other: &Mat<T>) {
+                self.zip_assign(other, ops::$op::$method)
+                    .unwrap_or_else(|e| panic!("{}", e));
+            }
+        }
+
+        impl<T: Scalar> ops::$assign for Mat<T> {
+            fn $assign_method(&mut self, other: Mat<T>) {
+                ops::$assign::$assign_method(self, &other);
             }
         }
-        result
+    };
+}
+
+impl_elementwise_op!(Add, add, AddAssign, add_assign, try_add);
+impl_elementwise_op!(Sub, sub, SubAssign, sub_assign, try_sub);
+
+impl<T: Scalar> ops::Mul for &Mat<T> {
+    type Output = Mat<T>;
+
+    fn mul(self, other: &Mat<T>) -> Mat<T> {
+        self.try_dot(other).unwrap_or_else(|e| panic!("{}", e))
     }
 }
 
-impl ops::Mul for &Mat {
-    type Output = Mat;
+impl<T: Scalar> ops::Mul<&Mat<T>> for Mat<T> {
+    type Output = Mat<T>;
 
-    fn mul(self, other: &Mat) -> Mat {
-        assert_eq!(
-            self.cols, other.rows,
-            "Matrix dimensions not compatible for multiplication"
-        );
+    fn mul(self, other: &Mat<T>) -> Mat<T> {
+        &self * other
+    }
+}
 
-        let mut result = Mat::zeros(self.rows, other.cols);
-        for i in 0..self.rows {
-            for j in 0..other.cols {
-                result[(i, j)] = (0..self.cols).map(|k| self[(i, k)] * other[(k, j)]).sum();
-            }
-        }
-        result
+impl<T: Scalar> ops::Mul<Mat<T>> for &Mat<T> {
+    type Output = Mat<T>;
+
+    fn mul(self, other: Mat<T>) -> Mat<T> {
+        self * &other
     }
 }
 
-impl ops::Mul<f64> for &Mat {
-    type Output = Mat;
+impl<T: Scalar> ops::Mul for Mat<T> {
+    type Output = Mat<T>;
 
-    fn mul(self, scalar: f64) -> Mat {
-        let mut result = self.clone();
-        for val in result.data.iter_mut() {
-            *val *= scalar;
-        }
-        result
+    fn mul(self, other: Mat<T>) -> Mat<T> {
+        &self * &other
     }
 }
 
-impl ops::Mul<&Mat> for f64 {
-    type Output = Mat;
+impl<T: Scalar> ops::MulAssign<&Mat<T>> for Mat<T> {
+    fn mul_assign(&mut self, other: &Mat<T>) {
+        *self = &*self * other;
+    }
+}
 
-    fn mul(self, m: &Mat) -> Mat {
-        let mut result = m.clone();
-        for val in result.data.iter_mut() {
-            *val *= self;
-        }
-        result
+impl<T: Scalar> ops::MulAssign for Mat<T> {
+    fn mul_assign(&mut self, other: Mat<T>) {
+        *self = &*self * &other;
     }
 }
 
-impl ops::Div<f64> for &Mat {
-    type Output = Mat;
+impl<T: Scalar> ops::Mul<T> for Mat<T> {
+    type Output = Mat<T>;
 
-    fn div(self, scalar: f64) -> Mat {
-        assert!(scalar != 0.0, "Cannot divide by zero");
-        let mut result = self.clone();
-        for val in result.data.iter_mut() {
-            *val /= scalar;
-        }
-        result
+    fn mul(mut self, scalar: T) -> Mat<T> {
+        self *= scalar;
+        self
+    }
+}
+
+impl<T: Scalar> ops::Mul<T> for &Mat<T> {
+    type Output = Mat<T>;
+
+    fn mul(self, scalar: T) -> Mat<T> {
+        self.clone() * scalar
+    }
+}
+
+impl<T: Scalar> ops::MulAssign<T> for Mat<T> {
+    fn mul_assign(&mut self, scalar: T) {
+        self.map_inplace(|val| val * scalar);
+    }
+}
+
+macro_rules! impl_scalar_mul {
+    ($($t:ty),*) => {
+        $(
+            impl ops::Mul<&Mat<$t>> for $t {
+                type Output = Mat<$t>;
+
+                fn mul(self, m: &Mat<$t>) -> Mat<$t> {
+                    self * m.clone()
+                }
+            }
+
+            impl ops::Mul<Mat<$t>> for $t {
+                type Output = Mat<$t>;
+
+                fn mul(self, mut m: Mat<$t>) -> Mat<$t> {
+                    m.map_inplace(|val| self * val);
+                    m
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_mul!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, Complex);
+
+impl<T: Scalar> ops::Div<T> for Mat<T> {
+    type Output = Mat<T>;
+
+    fn div(mut self, scalar: T) -> Mat<T> {
+        self /= scalar;
+        self
     }
 }
 
-impl ops::Neg for &Mat {
-    type Output = Mat;
+impl<T: Scalar> ops::Div<T> for &Mat<T> {
+    type Output = Mat<T>;
+
+    fn div(self, scalar: T) -> Mat<T> {
+        self.clone() / scalar
+    }
+}
 
-    fn neg(self) -> Mat {
-        let mut result = self.clone();
-        for val in result.data.iter_mut() {
-            *val = -(*val);
+impl<T: Scalar> ops::DivAssign<T> for Mat<T> {
+    fn div_assign(&mut self, scalar: T) {
+        if scalar == T::zero() {
+            panic!("{}", Error::DivisionByZero);
         }
-        result
+        self.map_inplace(|val| val / scalar);
+    }
+}
+
+impl<T: Scalar + ops::Neg<Output = T>> ops::Neg for Mat<T> {
+    type Output = Mat<T>;
+
+    fn neg(mut self) -> Mat<T> {
+        self.map_inplace(|val| -val);
+        self
+    }
+}
+
+impl<T: Scalar + ops::Neg<Output = T>> ops::Neg for &Mat<T> {
+    type Output = Mat<T>;
+
+    fn neg(self) -> Mat<T> {
+        -self.clone()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::f64::EPSILON;
+    use crate::assert_mat_approx_eq;
 
     #[test]
     fn test_new() {
-        let m = Mat::new(2, 3);
+        let m: Mat = Mat::new(2, 3);
         assert_eq!(m.rows, 2);
         assert_eq!(m.cols, 3);
         assert_eq!(m.data, vec![0.0; 6]);
@@ -330,7 +586,7 @@ mod tests {
 
     #[test]
     fn test_zeros() {
-        let m = Mat::zeros(3, 2);
+        let m: Mat = Mat::zeros(3, 2);
         assert_eq!(m.rows, 3);
         assert_eq!(m.cols, 2);
         assert!(m.data.iter().all(|&x| x == 0.0));
@@ -338,7 +594,7 @@ mod tests {
 
     #[test]
     fn test_ones() {
-        let m = Mat::ones(2, 3);
+        let m: Mat = Mat::ones(2, 3);
         assert_eq!(m.rows, 2);
         assert_eq!(m.cols, 3);
         assert!(m.data.iter().all(|&x| x == 1.0));
@@ -346,7 +602,7 @@ mod tests {
 
     #[test]
     fn test_eye() {
-        let m = Mat::eye(3);
+        let m: Mat = Mat::eye(3);
         assert_eq!(m.rows, 3);
         assert_eq!(m.cols, 3);
         for i in 0..3 {
@@ -387,7 +643,7 @@ mod tests {
     #[test]
     fn test_det() {
         let m = Mat::from_vec(3, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
-        assert!((m.det() - 0.0).abs() < EPSILON);
+        assert!((m.det() - 0.0).abs() < f64::EPSILON);
 
         let m2 = Mat::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
         assert_eq!(m2.det(), -2.0);
@@ -398,11 +654,83 @@ mod tests {
         let m = Mat::from_vec(2, 2, vec![4.0, 7.0, 2.0, 6.0]);
         let inv = m.inverse().unwrap();
         let expected = Mat::from_vec(2, 2, vec![0.6, -0.7, -0.2, 0.4]);
-        for i in 0..2 {
-            for j in 0..2 {
-                assert!((inv[(i, j)] - expected[(i, j)]).abs() < EPSILON);
-            }
-        }
+        assert_mat_approx_eq!(inv, expected, f64::EPSILON);
+
+        assert_eq!(Mat::ones(2, 3).inverse(), None);
+        assert_eq!(
+            Mat::ones(2, 3).try_inverse(),
+            Err(Error::NotSquare { rows: 2, cols: 3 })
+        );
+    }
+
+    #[test]
+    fn test_cofactor_adjugate() {
+        let m = Mat::from_vec(3, 3, vec![1.0, 2.0, 3.0, 0.0, 4.0, 5.0, 1.0, 0.0, 6.0]);
+        let adj = m.adjugate();
+        assert_mat_approx_eq!(m.dot(&adj), Mat::eye(3) * m.det(), 1e-12);
+        assert_eq!(m.try_cofactor(), Ok(adj.transpose()));
+        assert_eq!(Mat::from_vec(1, 1, vec![5.0]).adjugate(), Mat::ones(1, 1));
+        assert_eq!(
+            Mat::ones(2, 3).try_adjugate(),
+            Err(Error::NotSquare { rows: 2, cols: 3 })
+        );
+    }
+
+    #[test]
+    fn test_solve() {
+        let a = Mat::from_vec(3, 3, vec![3.0, 2.0, -1.0, 2.0, -2.0, 4.0, -1.0, 0.5, -1.0]);
+        let b = Mat::from_vec(3, 1, vec![1.0, -2.0, 0.0]);
+        let x = a.solve(&b).unwrap();
+        assert_mat_approx_eq!(x, Mat::from_vec(3, 1, vec![1.0, -2.0, -2.0]), 1e-12);
+    }
+
+    #[test]
+    fn test_solve_multiple_rhs() {
+        let a = Mat::from_vec(2, 2, vec![4.0, 7.0, 2.0, 6.0]);
+        let b = Mat::eye(2);
+        let x = a.solve(&b).unwrap();
+        let expected = a.inverse().unwrap();
+        assert_mat_approx_eq!(x, expected, 1e-12);
+    }
+
+    #[test]
+    fn test_solve_overdetermined() {
+        // Least-squares line through (0, 1), (1, 3), (2, 4), (3, 4).
+        let a = Mat::from_vec(4, 2, vec![1.0, 0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 3.0]);
+        let b = Mat::from_vec(4, 1, vec![1.0, 3.0, 4.0, 4.0]);
+        let x = a.solve(&b).unwrap();
+        assert_mat_approx_eq!(x, Mat::from_vec(2, 1, vec![1.5, 1.0]), 1e-12);
+    }
+
+    #[test]
+    fn test_solve_underdetermined() {
+        let a = Mat::from_vec(1, 2, vec![1.0, 1.0]);
+        let b = Mat::from_vec(1, 1, vec![2.0]);
+        let x = a.solve(&b).unwrap();
+        assert_mat_approx_eq!(x, Mat::ones(2, 1), 1e-12);
+    }
+
+    #[test]
+    fn test_solve_singular() {
+        let a = Mat::from_vec(3, 3, vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 1.0, 1.0, 1.0]);
+        let b = Mat::ones(3, 1);
+        assert_eq!(a.solve(&b), Err(Error::Singular));
+
+        let tall = Mat::from_vec(3, 2, vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+        assert_eq!(tall.solve(&b), Err(Error::Singular));
+    }
+
+    #[test]
+    fn test_solve_shape_mismatch() {
+        let a = Mat::eye(3);
+        let b = Mat::ones(2, 1);
+        assert_eq!(
+            a.solve(&b),
+            Err(Error::ShapeMismatch {
+                left: (3, 3),
+                right: (2, 1)
+            })
+        );
     }
 
     #[test]
@@ -431,7 +759,7 @@ mod tests {
 
     #[test]
     fn test_scalar_mul() {
-        let m = Mat::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        let m: Mat = Mat::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
         let result = 2.0 * &m;
         assert_eq!(result.data, vec![2.0, 4.0, 6.0, 8.0]);
     }
@@ -441,6 +769,14 @@ mod tests {
         let m = Mat::from_vec(2, 2, vec![2.0, 4.0, 6.0, 8.0]);
         let result = &m / 2.0;
         assert_eq!(result.data, vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(m.try_div(2.0), Ok(result));
+        assert_eq!(m.try_div(0.0), Err(Error::DivisionByZero));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot divide by zero.")]
+    fn test_div_by_zero_panics() {
+        let _ = Mat::from_vec(1, 2, vec![1, 2]) / 0;
     }
 
     #[test]
@@ -449,4 +785,188 @@ mod tests {
         let result = -&m;
         assert_eq!(result.data, vec![-1.0, 2.0, -3.0, 4.0]);
     }
+
+    #[test]
+    fn test_integer_matrix() {
+        let fib = Mat::from_vec(2, 2, vec![1u64, 1, 1, 0]);
+        assert_eq!(fib.pow(90)[(0, 1)], 2_880_067_194_370_816_120);
+
+        let m = Mat::from_vec(2, 2, vec![1i64, -2, 3, 4]);
+        assert_eq!((&m + &Mat::eye(2)).data, vec![2, -2, 3, 5]);
+        assert_eq!((-&m).data, vec![-1, 2, -3, -4]);
+        assert_eq!((3 * &m).data, vec![3, -6, 9, 12]);
+        assert_eq!(m.trace(), 5);
+    }
+
+    #[test]
+    fn test_f32_matrix() {
+        let m: Mat<f32> = Mat::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        let result = &m * &Mat::eye(2);
+        assert_eq!(result, m);
+        assert_eq!((&m / 2.0).data, vec![0.5, 1.0, 1.5, 2.0]);
+    }
+
+    #[test]
+    fn test_complex_matrix() {
+        let i = Complex::new(0.0, 1.0);
+        let one = Complex::new(1.0, 0.0);
+        let m = Mat::from_vec(2, 2, vec![one, i, -i, one]);
+
+        let sq = &m * &m;
+        assert_eq!(sq[(0, 0)], Complex::new(2.0, 0.0));
+        assert_eq!(sq[(0, 1)], Complex::new(0.0, 2.0));
+        assert_eq!(m.trace(), Complex::new(2.0, 0.0));
+
+        let scaled = i * &m;
+        assert_eq!(scaled[(0, 0)], i);
+        assert_eq!(scaled[(1, 0)], one);
+        assert_eq!((&scaled - &scaled), Mat::zeros(2, 2));
+        assert_eq!((&m / i)[(0, 1)], one);
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(Mat::<f64>::try_new(0, 3).unwrap().shape(), (0, 3));
+        assert_eq!(Mat::<f64>::try_new(2, 3).unwrap().shape(), (2, 3));
+        assert_eq!(Mat::<f64>::zeros(2, 0).shape(), (2, 0));
+        assert_eq!(Mat::<f64>::eye(0).shape(), (0, 0));
+    }
+
+    #[test]
+    fn test_try_from_vec() {
+        assert_eq!(
+            Mat::try_from_vec(2, 2, vec![1.0, 2.0, 3.0]),
+            Err(Error::LengthMismatch {
+                expected: 4,
+                actual: 3
+            })
+        );
+        assert!(Mat::try_from_vec(1, 3, vec![1.0, 2.0, 3.0]).is_ok());
+    }
+
+    #[test]
+    fn test_get() {
+        let mut m = Mat::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(m.get(1, 0), Ok(&3.0));
+        assert_eq!(
+            m.get(2, 0),
+            Err(Error::IndexOutOfBounds { index: 2, bound: 2 })
+        );
+        *m.get_mut(0, 1).unwrap() = 5.0;
+        assert_eq!(m[(0, 1)], 5.0);
+        assert!(m.get_mut(0, 2).is_err());
+    }
+
+    #[test]
+    fn test_try_arithmetic() {
+        let a: Mat = Mat::ones(2, 3);
+        let b = Mat::ones(3, 2);
+        let mismatch = Err(Error::ShapeMismatch {
+            left: (2, 3),
+            right: (3, 2),
+        });
+        assert_eq!(a.try_add(&b), mismatch);
+        assert_eq!(a.try_sub(&b), mismatch);
+        assert_eq!(
+            a.try_dot(&a),
+            Err(Error::ShapeMismatch {
+                left: (2, 3),
+                right: (2, 3),
+            })
+        );
+        assert_eq!(a.try_dot(&b).unwrap().data, vec![3.0; 4]);
+        assert_eq!(a.try_add(&a).unwrap().data, vec![2.0; 6]);
+    }
+
+    #[test]
+    fn test_try_square_ops() {
+        let m = Mat::ones(2, 3);
+        let not_square = Error::NotSquare { rows: 2, cols: 3 };
+        assert_eq!(m.try_trace(), Err(not_square));
+        assert_eq!(m.try_det(), Err(not_square));
+        assert_eq!(m.try_pow(2), Err(not_square));
+        assert_eq!(m.try_inverse(), Err(not_square));
+        assert_eq!(Mat::ones(2, 2).try_inverse(), Err(Error::Singular));
+    }
+
+    #[test]
+    fn test_try_indexing() {
+        let m = Mat::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(m.try_get_row(1).unwrap().data, vec![3.0, 4.0]);
+        assert_eq!(m.try_get_col(1).unwrap().data, vec![2.0, 4.0]);
+        assert_eq!(
+            m.try_get_row(2),
+            Err(Error::IndexOutOfBounds { index: 2, bound: 2 })
+        );
+        assert_eq!(m.try_sub_matrix(0, 0).unwrap().data, vec![4.0]);
+        assert_eq!(Mat::<f64>::ones(1, 3).sub_matrix(0, 2).shape(), (0, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix shapes 2x2 and 3x3 are not compatible.")]
+    fn test_add_shape_mismatch_panics() {
+        let _ = &Mat::<f64>::eye(2) + &Mat::eye(3);
+    }
+
+    #[test]
+    fn test_owned_ops() {
+        let a: Mat = Mat::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b = Mat::from_vec(2, 2, vec![5.0, 6.0, 7.0, 8.0]);
+
+        assert_eq!((a.clone() + b.clone()).data, vec![6.0, 8.0, 10.0, 12.0]);
+        assert_eq!((a.clone() + &b).data, vec![6.0, 8.0, 10.0, 12.0]);
+        assert_eq!((&a + b.clone()).data, vec![6.0, 8.0, 10.0, 12.0]);
+        assert_eq!((b.clone() - a.clone()).data, vec![4.0; 4]);
+        assert_eq!((&a - b.clone()).data, vec![-4.0; 4]);
+        assert_eq!((a.clone() - &b).data, vec![-4.0; 4]);
+
+        let product = &a * &b;
+        assert_eq!(a.clone() * b.clone(), product);
+        assert_eq!(a.clone() * &b, product);
+        assert_eq!(&a * b.clone(), product);
+
+        assert_eq!((a.clone() * 2.0).data, vec![2.0, 4.0, 6.0, 8.0]);
+        assert_eq!((2.0 * a.clone()).data, vec![2.0, 4.0, 6.0, 8.0]);
+        assert_eq!((a.clone() / 2.0).data, vec![0.5, 1.0, 1.5, 2.0]);
+        assert_eq!((-a.clone()).data, vec![-1.0, -2.0, -3.0, -4.0]);
+    }
+
+    #[test]
+    fn test_compound_assign() {
+        let a = Mat::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        let mut m = a.clone();
+
+        m += &a;
+        assert_eq!(m.data, vec![2.0, 4.0, 6.0, 8.0]);
+        m -= a.clone();
+        assert_eq!(m, a);
+        m *= 3.0;
+        assert_eq!(m.data, vec![3.0, 6.0, 9.0, 12.0]);
+        m /= 3.0;
+        assert_eq!(m, a);
+        m *= &Mat::eye(2);
+        assert_eq!(m, a);
+        m *= a.clone();
+        assert_eq!(m, &a * &a);
+    }
+
+    #[test]
+    fn test_owned_ops_reuse_buffer() {
+        let a = Mat::ones(3, 3);
+        let b = Mat::ones(3, 3);
+        let ptr = b.data.as_ptr();
+        let sum = &a + b;
+        assert_eq!(sum.data.as_ptr(), ptr);
+
+        let ptr = sum.data.as_ptr();
+        let scaled = sum * 2.0;
+        assert_eq!(scaled.data.as_ptr(), ptr);
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix shapes 2x2 and 3x3 are not compatible.")]
+    fn test_add_assign_shape_mismatch_panics() {
+        let mut m: Mat = Mat::eye(2);
+        m += &Mat::eye(3);
+    }
 }
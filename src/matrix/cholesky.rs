@@ -0,0 +1,436 @@
+use super::Mat;
+use crate::Error;
+
+/// Cholesky factorization `A = L * Lᵀ` of a symmetric positive-definite
+/// matrix. Only the lower triangle of `A` is read.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Cholesky {
+    l: Mat,
+}
+
+/// `P * A * Pᵀ = L * D * Lᵀ` factorization of a symmetric matrix with
+/// Bunch–Kaufman pivoting: `L` is unit lower triangular and `D` block
+/// diagonal with `1 x 1` and `2 x 2` blocks. Unlike [`Cholesky`] it accepts
+/// any nonsingular symmetric matrix, including indefinite ones with a zero
+/// diagonal. `perm[i]` is the row and column of `A` that ended up in
+/// position `i`, as for [`Lu`](super::Lu). Only the lower triangle of `A` is
+/// read.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Ldlt {
+    l: Mat,
+    d: Vec<f64>,
+    /// Subdiagonal of `D`, non-zero exactly at the first index of each
+    /// `2 x 2` block.
+    e: Vec<f64>,
+    perm: Vec<usize>,
+}
+
+/// Bunch–Kaufman threshold `(1 + sqrt(17)) / 8`, which bounds the growth of
+/// the entries of `L` and `D`.
+const BUNCH_KAUFMAN_ALPHA: f64 = 0.6403882032022076;
+
+impl Mat {
+    pub fn cholesky(&self) -> Result<Cholesky, Error> {
+        self.check_square()?;
+        let n = self.rows;
+        let mut l = Mat::zeros(n, n);
+        for j in 0..n {
+            let d = self[(j, j)] - (0..j).map(|k| l[(j, k)] * l[(j, k)]).sum::<f64>();
+            if d <= 0.0 || d.is_nan() {
+                return Err(Error::NotPositiveDefinite { pivot: j });
+            }
+            let d = d.sqrt();
+            l[(j, j)] = d;
+            for i in j + 1..n {
+                let s: f64 = (0..j).map(|k| l[(i, k)] * l[(j, k)]).sum();
+                l[(i, j)] = (self[(i, j)] - s) / d;
+            }
+        }
+
+        Ok(Cholesky { l })
+    }
+
+    /// Fails with `Singular` only when a whole pivot column of the reduced
+    /// matrix is exactly zero, i.e. when `A` is singular.
+    pub fn ldlt(&self) -> Result<Ldlt, Error> {
+        self.check_square()?;
+        let n = self.rows;
+        // Symmetric working copy, filled from the lower triangle.
+        let mut a = Mat::from_fn(
+            n,
+            n,
+            |i, j| if i >= j { self[(i, j)] } else { self[(j, i)] },
+        );
+        let mut l = Mat::eye(n);
+        let mut d = vec![0.0; n];
+        let mut e = vec![0.0; n.saturating_sub(1)];
+        let mut perm: Vec<usize> = (0..n).collect();
+
+        let mut k = 0;
+        while k < n {
+            let (r, col_max) =
+                (k + 1..n)
+                    .map(|i| (i, a[(i, k)].abs()))
+                    .fold(
+                        (k, 0.0),
+                        |best, cur| if cur.1 > best.1 { cur } else { best },
+                    );
+            let diag = a[(k, k)].abs();
+            if diag == 0.0 && col_max == 0.0 {
+                return Err(Error::Singular);
+            }
+
+            let block = if diag >= BUNCH_KAUFMAN_ALPHA * col_max {
+                1
+            } else {
+                let row_max = (k..n)
+                    .filter(|&j| j != r)
+                    .map(|j| a[(r, j)].abs())
+                    .fold(0.0, f64::max);
+                if diag * row_max >= BUNCH_KAUFMAN_ALPHA * col_max * col_max {
+                    1
+                } else if a[(r, r)].abs() >= BUNCH_KAUFMAN_ALPHA * row_max {
+                    swap_symmetric(&mut a, &mut l, &mut perm, k, r);
+                    1
+                } else {
+                    swap_symmetric(&mut a, &mut l, &mut perm, k + 1, r);
+                    2
+                }
+            };
+
+            if block == 1 {
+                let pivot = a[(k, k)];
+                d[k] = pivot;
+                for i in k + 1..n {
+                    l[(i, k)] = a[(i, k)] / pivot;
+                }
+                for j in k + 1..n {
+                    for i in j..n {
+                        a[(i, j)] -= l[(i, k)] * a[(j, k)];
+                        a[(j, i)] = a[(i, j)];
+                    }
+                }
+            } else {
+                let (d0, b, d1) = (a[(k, k)], a[(k + 1, k)], a[(k + 1, k + 1)]);
+                let det = d0 * d1 - b * b;
+                if det == 0.0 {
+                    return Err(Error::Singular);
+                }
+                d[k] = d0;
+                d[k + 1] = d1;
+                e[k] = b;
+                for i in k + 2..n {
+                    let (c0, c1) = (a[(i, k)], a[(i, k + 1)]);
+                    l[(i, k)] = (d1 * c0 - b * c1) / det;
+                    l[(i, k + 1)] = (d0 * c1 - b * c0) / det;
+                }
+                for j in k + 2..n {
+                    for i in j..n {
+                        a[(i, j)] -= l[(i, k)] * a[(j, k)] + l[(i, k + 1)] * a[(j, k + 1)];
+                        a[(j, i)] = a[(i, j)];
+                    }
+                }
+            }
+            k += block;
+        }
+
+        Ok(Ldlt { l, d, e, perm })
+    }
+}
+
+/// Swaps positions `i < j` of the reduced matrix `a`, symmetrically, along
+/// with the rows of the finished columns of `l` and the permutation.
+fn swap_symmetric(a: &mut Mat, l: &mut Mat, perm: &mut [usize], i: usize, j: usize) {
+    if i == j {
+        return;
+    }
+    a.swap_rows(i, j);
+    for row in 0..a.rows {
+        let tmp = a[(row, i)];
+        a[(row, i)] = a[(row, j)];
+        a[(row, j)] = tmp;
+    }
+    for col in 0..i {
+        let tmp = l[(i, col)];
+        l[(i, col)] = l[(j, col)];
+        l[(j, col)] = tmp;
+    }
+    perm.swap(i, j);
+}
+
+fn check_rhs(n: usize, b: &Mat) -> Result<(), Error> {
+    if b.rows != n {
+        return Err(Error::ShapeMismatch {
+            left: (n, n),
+            right: b.shape(),
+        });
+    }
+    Ok(())
+}
+
+/// Solves `L * y = b` in place, `L` lower triangular.
+fn forward_substitute(l: &Mat, unit_diag: bool, x: &mut Mat) {
+    let n = l.rows;
+    for c in 0..x.cols {
+        for i in 0..n {
+            let s: f64 = (0..i).map(|k| l[(i, k)] * x[(k, c)]).sum();
+            x[(i, c)] -= s;
+            if !unit_diag {
+                x[(i, c)] /= l[(i, i)];
+            }
+        }
+    }
+}
+
+/// Solves `Lᵀ * x = y` in place, `L` lower triangular.
+fn back_substitute_transposed(l: &Mat, unit_diag: bool, x: &mut Mat) {
+    let n = l.rows;
+    for c in 0..x.cols {
+        for i in (0..n).rev() {
+            let s: f64 = (i + 1..n).map(|k| l[(k, i)] * x[(k, c)]).sum();
+            x[(i, c)] -= s;
+            if !unit_diag {
+                x[(i, c)] /= l[(i, i)];
+            }
+        }
+    }
+}
+
+impl Cholesky {
+    pub fn l(&self) -> Mat {
+        self.l.clone()
+    }
+
+    pub fn det(&self) -> f64 {
+        (0..self.l.rows)
+            .map(|i| self.l[(i, i)] * self.l[(i, i)])
+            .product()
+    }
+
+    pub fn solve(&self, b: &Mat) -> Result<Mat, Error> {
+        check_rhs(self.l.rows, b)?;
+        let mut x = b.clone();
+        forward_substitute(&self.l, false, &mut x);
+        back_substitute_transposed(&self.l, false, &mut x);
+        Ok(x)
+    }
+
+    pub fn inverse(&self) -> Result<Mat, Error> {
+        self.solve(&Mat::eye(self.l.rows))
+    }
+}
+
+impl Ldlt {
+    pub fn l(&self) -> Mat {
+        self.l.clone()
+    }
+
+    /// The block diagonal factor `D`.
+    pub fn d(&self) -> Mat {
+        let n = self.d.len();
+        let mut d = Mat::zeros(n, n);
+        for (i, &v) in self.d.iter().enumerate() {
+            d[(i, i)] = v;
+        }
+        for (i, &v) in self.e.iter().enumerate() {
+            d[(i + 1, i)] = v;
+            d[(i, i + 1)] = v;
+        }
+        d
+    }
+
+    pub fn p(&self) -> Mat {
+        let n = self.perm.len();
+        let mut p = Mat::zeros(n, n);
+        for (i, &j) in self.perm.iter().enumerate() {
+            p[(i, j)] = 1.0;
+        }
+        p
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// Product of the determinants of the blocks of `D`; the symmetric
+    /// permutation does not change the sign.
+    pub fn det(&self) -> f64 {
+        let n = self.d.len();
+        let mut det = 1.0;
+        let mut k = 0;
+        while k < n {
+            if k + 1 < n && self.e[k] != 0.0 {
+                det *= self.d[k] * self.d[k + 1] - self.e[k] * self.e[k];
+                k += 2;
+            } else {
+                det *= self.d[k];
+                k += 1;
+            }
+        }
+        det
+    }
+
+    pub fn solve(&self, b: &Mat) -> Result<Mat, Error> {
+        check_rhs(self.l.rows, b)?;
+        let mut x = Mat::from_fn(b.rows, b.cols, |i, c| b[(self.perm[i], c)]);
+        self.solve_in_place(&mut x);
+        let mut out = Mat::zeros(b.rows, b.cols);
+        for (i, &p) in self.perm.iter().enumerate() {
+            for c in 0..b.cols {
+                out[(p, c)] = x[(i, c)];
+            }
+        }
+        Ok(out)
+    }
+
+    pub fn inverse(&self) -> Result<Mat, Error> {
+        self.solve(&Mat::eye(self.l.rows))
+    }
+
+    /// Solves `L * D * Lᵀ * x = b` in place.
+    fn solve_in_place(&self, x: &mut Mat) {
+        forward_substitute(&self.l, true, x);
+        let n = self.d.len();
+        let mut k = 0;
+        while k < n {
+            if k + 1 < n && self.e[k] != 0.0 {
+                let (d0, b, d1) = (self.d[k], self.e[k], self.d[k + 1]);
+                let det = d0 * d1 - b * b;
+                for c in 0..x.cols {
+                    let (y0, y1) = (x[(k, c)], x[(k + 1, c)]);
+                    x[(k, c)] = (d1 * y0 - b * y1) / det;
+                    x[(k + 1, c)] = (d0 * y1 - b * y0) / det;
+                }
+                k += 2;
+            } else {
+                for c in 0..x.cols {
+                    x[(k, c)] /= self.d[k];
+                }
+                k += 1;
+            }
+        }
+        back_substitute_transposed(&self.l, true, x);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_mat_approx_eq;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn spd() -> Mat {
+        Mat::from_vec(
+            3,
+            3,
+            vec![4.0, 12.0, -16.0, 12.0, 37.0, -43.0, -16.0, -43.0, 98.0],
+        )
+    }
+
+    #[test]
+    fn test_cholesky() {
+        let chol = spd().cholesky().unwrap();
+        let expected = Mat::from_vec(3, 3, vec![2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0]);
+        assert_mat_approx_eq!(chol.l(), expected, 1e-12);
+        assert!((chol.det() - 36.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cholesky_solve_inverse() {
+        let a = spd();
+        let chol = a.cholesky().unwrap();
+        let b = Mat::from_vec(3, 2, vec![1.0, 0.0, 2.0, 1.0, 3.0, -1.0]);
+        let x = chol.solve(&b).unwrap();
+        assert_mat_approx_eq!(a.dot(&x), b, 1e-9);
+        assert_mat_approx_eq!(a.dot(&chol.inverse().unwrap()), Mat::eye(3), 1e-9);
+    }
+
+    #[test]
+    fn test_cholesky_not_positive_definite() {
+        let a = Mat::from_vec(3, 3, vec![1.0, 2.0, 0.0, 2.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(a.cholesky(), Err(Error::NotPositiveDefinite { pivot: 1 }));
+        assert_eq!(
+            Mat::zeros(2, 3).cholesky(),
+            Err(Error::NotSquare { rows: 2, cols: 3 })
+        );
+    }
+
+    fn check_ldlt(a: &Mat) {
+        let ldlt = a.ldlt().unwrap();
+        let (l, d, p) = (ldlt.l(), ldlt.d(), ldlt.p());
+        assert_mat_approx_eq!(
+            l.dot(&d).dot(&l.transpose()),
+            p.dot(a).dot(&p.transpose()),
+            1e-12
+        );
+        assert!((ldlt.det() - a.det()).abs() < 1e-12 * a.det().abs().max(1.0));
+
+        let n = a.shape().0;
+        let b = Mat::from_fn(n, 2, |i, j| (i + 2 * j) as f64 - 1.5);
+        assert_mat_approx_eq!(a.dot(&ldlt.solve(&b).unwrap()), b, 1e-12);
+        assert_mat_approx_eq!(a.dot(&ldlt.inverse().unwrap()), Mat::eye(n), 1e-12);
+    }
+
+    #[test]
+    fn test_ldlt_indefinite() {
+        check_ldlt(&Mat::from_vec(
+            3,
+            3,
+            vec![1.0, 2.0, 0.0, 2.0, 1.0, 3.0, 0.0, 3.0, -2.0],
+        ));
+        check_ldlt(&spd());
+    }
+
+    #[test]
+    fn test_ldlt_zero_diagonal() {
+        let swap = Mat::from_vec(2, 2, vec![0.0, 1.0, 1.0, 0.0]);
+        check_ldlt(&swap);
+        let ldlt = swap.ldlt().unwrap();
+        assert_eq!(ldlt.d(), swap);
+        assert_eq!(ldlt.det(), -1.0);
+        let x = ldlt.solve(&Mat::from_vec(2, 1, vec![2.0, 3.0])).unwrap();
+        assert_eq!(x, Mat::from_vec(2, 1, vec![3.0, 2.0]));
+
+        // Needs a 2 x 2 pivot after a symmetric swap, then a 1 x 1 one.
+        check_ldlt(&Mat::from_vec(
+            3,
+            3,
+            vec![0.0, 0.0, 2.0, 0.0, 1.0, 0.0, 2.0, 0.0, 0.0],
+        ));
+        check_ldlt(&Mat::from_vec(
+            4,
+            4,
+            vec![
+                1e-3, 4.0, 0.0, 1.0, 4.0, 2.0, -1.0, 0.0, 0.0, -1.0, 0.0, 3.0, 1.0, 0.0, 3.0, -5.0,
+            ],
+        ));
+
+        let tiny = Mat::from_vec(2, 2, vec![1e-300, 1.0, 1.0, 0.0]);
+        check_ldlt(&tiny);
+        let scaled = Mat::from_vec(2, 2, vec![1e-300, 0.0, 0.0, 1e-300]);
+        assert!(scaled.ldlt().is_ok());
+    }
+
+    #[test]
+    fn test_ldlt_random_indefinite() {
+        let mut rng = StdRng::seed_from_u64(11);
+        for n in [2, 5, 12] {
+            let g = Mat::random_normal(n, n, 0.0, 1.0, &mut rng);
+            let mut a = &g + &g.transpose();
+            for i in 0..n {
+                a[(i, i)] = 0.0;
+            }
+            check_ldlt(&a);
+        }
+    }
+
+    #[test]
+    fn test_ldlt_singular() {
+        assert_eq!(Mat::zeros(2, 2).ldlt(), Err(Error::Singular));
+        let rank_one = Mat::from_vec(2, 2, vec![1.0, 1.0, 1.0, 1.0]);
+        assert_eq!(rank_one.ldlt(), Err(Error::Singular));
+        let block = Mat::from_vec(3, 3, vec![0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(block.ldlt(), Err(Error::Singular));
+    }
+}
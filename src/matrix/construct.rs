@@ -0,0 +1,321 @@
+use super::Mat;
+use crate::scalar::Scalar;
+use crate::Error;
+
+impl<T: Scalar> Mat<T> {
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let data = (0..rows)
+            .flat_map(|i| (0..cols).map(move |j| (i, j)))
+            .map(|(i, j)| f(i, j))
+            .collect();
+        Self { data, rows, cols }
+    }
+
+    pub fn from_rows(rows: &[&[T]]) -> Self {
+        Self::try_from_rows(rows).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Fails with `Empty` when `rows` is empty, as there is no row to take
+    /// the width from. Rows of length 0 give an empty matrix.
+    pub fn try_from_rows(rows: &[&[T]]) -> Result<Self, Error> {
+        let cols = rows.first().ok_or(Error::Empty)?.len();
+        if let Some(row) = rows.iter().find(|row| row.len() != cols) {
+            return Err(Error::ShapeMismatch {
+                left: (1, cols),
+                right: (1, row.len()),
+            });
+        }
+        Self::try_from_vec(rows.len(), cols, rows.concat())
+    }
+
+    pub fn from_cols(cols: &[&[T]]) -> Self {
+        Self::try_from_cols(cols).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_from_cols(cols: &[&[T]]) -> Result<Self, Error> {
+        Self::try_from_rows(cols).map(|m| m.transpose())
+    }
+
+    /// Square matrix with `values` on the diagonal. Named `from_diag` because
+    /// `diag` already borrows the diagonal of an existing matrix.
+    pub fn from_diag(values: &[T]) -> Self {
+        let n = values.len();
+        Self::from_fn(n, n, |i, j| if i == j { values[i] } else { T::zero() })
+    }
+
+    /// Concatenates matrices with the same number of rows side by side.
+    pub fn hstack(blocks: &[&Self]) -> Self {
+        Self::try_hstack(blocks).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_hstack(blocks: &[&Self]) -> Result<Self, Error> {
+        let first = blocks.first().ok_or(Error::Empty)?;
+        if let Some(block) = blocks.iter().find(|b| b.rows != first.rows) {
+            return Err(Error::ShapeMismatch {
+                left: first.shape(),
+                right: block.shape(),
+            });
+        }
+        let cols = blocks.iter().map(|b| b.cols).sum();
+        let mut data = Vec::with_capacity(first.rows * cols);
+        for i in 0..first.rows {
+            for block in blocks {
+                data.extend_from_slice(&block.data[i * block.cols..(i + 1) * block.cols]);
+            }
+        }
+        Self::try_from_vec(first.rows, cols, data)
+    }
+
+    /// Concatenates matrices with the same number of columns on top of each
+    /// other.
+    pub fn vstack(blocks: &[&Self]) -> Self {
+        Self::try_vstack(blocks).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_vstack(blocks: &[&Self]) -> Result<Self, Error> {
+        let first = blocks.first().ok_or(Error::Empty)?;
+        if let Some(block) = blocks.iter().find(|b| b.cols != first.cols) {
+            return Err(Error::ShapeMismatch {
+                left: first.shape(),
+                right: block.shape(),
+            });
+        }
+        let rows = blocks.iter().map(|b| b.rows).sum();
+        let data = blocks.iter().flat_map(|b| b.data.iter().copied()).collect();
+        Self::try_from_vec(rows, first.cols, data)
+    }
+
+    /// Places the blocks along the diagonal of an otherwise zero matrix.
+    pub fn block_diag(blocks: &[&Self]) -> Self {
+        let rows = blocks.iter().map(|b| b.rows).sum();
+        let cols = blocks.iter().map(|b| b.cols).sum();
+        let mut m = Self::filled(rows, cols, T::zero());
+        let (mut r, mut c) = (0, 0);
+        for block in blocks {
+            m.block_mut(r..r + block.rows, c..c + block.cols)
+                .assign(block.view());
+            r += block.rows;
+            c += block.cols;
+        }
+        m
+    }
+
+    /// Same entries in row-major order, laid out with a new shape.
+    pub fn reshape(&self, rows: usize, cols: usize) -> Self {
+        self.try_reshape(rows, cols)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_reshape(&self, rows: usize, cols: usize) -> Result<Self, Error> {
+        Self::try_from_vec(rows, cols, self.data.clone())
+    }
+
+    /// Repeats the whole matrix `rows` times vertically and `cols` times
+    /// horizontally.
+    pub fn tile(&self, rows: usize, cols: usize) -> Self {
+        Self::from_fn(self.rows * rows, self.cols * cols, |i, j| {
+            self[(i % self.rows, j % self.cols)]
+        })
+    }
+
+    /// Repeats every entry into a `rows x cols` block, so `[a b].repeat(1, 2)`
+    /// is `[a a b b]`.
+    pub fn repeat(&self, rows: usize, cols: usize) -> Self {
+        Self::from_fn(self.rows * rows, self.cols * cols, |i, j| {
+            self[(i / rows, j / cols)]
+        })
+    }
+
+    /// Inserts `row` before row `index`; `index == rows` appends it.
+    pub fn insert_row(&mut self, index: usize, row: &[T]) {
+        self.try_insert_row(index, row)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_insert_row(&mut self, index: usize, row: &[T]) -> Result<(), Error> {
+        self.check_insert(index, self.rows)?;
+        if row.len() != self.cols {
+            return Err(Error::ShapeMismatch {
+                left: self.shape(),
+                right: (1, row.len()),
+            });
+        }
+        let at = index * self.cols;
+        self.data.splice(at..at, row.iter().copied());
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Inserts `col` before column `index`; `index == cols` appends it.
+    pub fn insert_col(&mut self, index: usize, col: &[T]) {
+        self.try_insert_col(index, col)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_insert_col(&mut self, index: usize, col: &[T]) -> Result<(), Error> {
+        self.check_insert(index, self.cols)?;
+        if col.len() != self.rows {
+            return Err(Error::ShapeMismatch {
+                left: self.shape(),
+                right: (col.len(), 1),
+            });
+        }
+        let cols = self.cols + 1;
+        let mut data = Vec::with_capacity(self.rows * cols);
+        for (i, &val) in col.iter().enumerate() {
+            let row = &self.data[i * self.cols..(i + 1) * self.cols];
+            data.extend_from_slice(&row[..index]);
+            data.push(val);
+            data.extend_from_slice(&row[index..]);
+        }
+        self.data = data;
+        self.cols = cols;
+        Ok(())
+    }
+
+    /// Removes row `index` and returns its entries.
+    pub fn remove_row(&mut self, index: usize) -> Vec<T> {
+        self.try_remove_row(index)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_remove_row(&mut self, index: usize) -> Result<Vec<T>, Error> {
+        self.check_row(index)?;
+        let at = index * self.cols;
+        let row = self.data.drain(at..at + self.cols).collect();
+        self.rows -= 1;
+        Ok(row)
+    }
+
+    /// Removes column `index` and returns its entries.
+    pub fn remove_col(&mut self, index: usize) -> Vec<T> {
+        self.try_remove_col(index)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_remove_col(&mut self, index: usize) -> Result<Vec<T>, Error> {
+        self.check_col(index)?;
+        let col = (0..self.rows).map(|i| self[(i, index)]).collect();
+        let cols = self.cols;
+        let mut k = 0;
+        self.data.retain(|_| {
+            k += 1;
+            (k - 1) % cols != index
+        });
+        self.cols -= 1;
+        Ok(col)
+    }
+
+    fn check_insert(&self, index: usize, len: usize) -> Result<(), Error> {
+        if index > len {
+            return Err(Error::IndexOutOfBounds { index, bound: len });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_fn_rows_cols() {
+        let m = Mat::from_fn(2, 3, |i, j| (i * 3 + j) as f64);
+        assert_eq!(m, Mat::from_vec(2, 3, vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]));
+        assert_eq!(Mat::from_rows(&[&[0.0, 1.0, 2.0], &[3.0, 4.0, 5.0]]), m);
+        assert_eq!(Mat::from_cols(&[&[0.0, 3.0], &[1.0, 4.0], &[2.0, 5.0]]), m);
+
+        assert_eq!(Mat::<f64>::try_from_rows(&[]), Err(Error::Empty));
+        assert_eq!(Mat::<f64>::from_rows(&[&[], &[]]).shape(), (2, 0));
+        assert_eq!(Mat::<f64>::from_cols(&[&[], &[]]).shape(), (0, 2));
+        assert_eq!(
+            Mat::try_from_rows(&[&[1.0, 2.0], &[3.0]]),
+            Err(Error::ShapeMismatch {
+                left: (1, 2),
+                right: (1, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_diag_block_diag() {
+        let d = Mat::from_diag(&[1, 2, 3]);
+        assert_eq!(d.data, vec![1, 0, 0, 0, 2, 0, 0, 0, 3]);
+
+        let a = Mat::ones(1, 2);
+        let b = Mat::filled(2, 1, 2.0);
+        let m = Mat::block_diag(&[&a, &b]);
+        assert_eq!(m.shape(), (3, 3));
+        assert_eq!(m.data, vec![1.0, 1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 2.0]);
+
+        assert_eq!(Mat::<f64>::from_diag(&[]).shape(), (0, 0));
+        assert_eq!(Mat::<f64>::block_diag(&[]).shape(), (0, 0));
+    }
+
+    #[test]
+    fn test_stack() {
+        let a = Mat::from_vec(2, 1, vec![1.0, 2.0]);
+        let b = Mat::from_vec(2, 2, vec![3.0, 4.0, 5.0, 6.0]);
+        let h = Mat::hstack(&[&a, &b]);
+        assert_eq!(h, Mat::from_vec(2, 3, vec![1.0, 3.0, 4.0, 2.0, 5.0, 6.0]));
+
+        let v = Mat::vstack(&[&b, &a.transpose()]);
+        assert_eq!(v, Mat::from_vec(3, 2, vec![3.0, 4.0, 5.0, 6.0, 1.0, 2.0]));
+
+        assert_eq!(Mat::<f64>::try_hstack(&[]), Err(Error::Empty));
+        assert_eq!(
+            Mat::try_vstack(&[&a, &b]),
+            Err(Error::ShapeMismatch {
+                left: (2, 1),
+                right: (2, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_reshape_tile_repeat() {
+        let m = Mat::from_vec(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(m.reshape(3, 2).data, m.data);
+        assert_eq!(m.reshape(3, 2).shape(), (3, 2));
+        assert_eq!(
+            m.try_reshape(4, 2),
+            Err(Error::LengthMismatch {
+                expected: 8,
+                actual: 6
+            })
+        );
+
+        let r = Mat::from_vec(1, 2, vec![1.0, 2.0]);
+        assert_eq!(
+            r.tile(2, 2).data,
+            vec![1.0, 2.0, 1.0, 2.0, 1.0, 2.0, 1.0, 2.0]
+        );
+        assert_eq!(r.repeat(1, 2).data, vec![1.0, 1.0, 2.0, 2.0]);
+        assert_eq!(r.repeat(2, 1).shape(), (2, 2));
+        assert_eq!(r.tile(0, 3).shape(), (0, 6));
+        assert_eq!(r.repeat(2, 0).shape(), (2, 0));
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let mut m = Mat::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        m.insert_row(1, &[9.0, 9.0]);
+        assert_eq!(m.data, vec![1.0, 2.0, 9.0, 9.0, 3.0, 4.0]);
+        m.insert_col(2, &[7.0, 8.0, 9.0]);
+        assert_eq!(m.shape(), (3, 3));
+        assert_eq!(m.get_col(2).data, vec![7.0, 8.0, 9.0]);
+
+        assert_eq!(m.remove_col(0), vec![1.0, 9.0, 3.0]);
+        assert_eq!(m.remove_row(1), vec![9.0, 8.0]);
+        assert_eq!(m, Mat::from_vec(2, 2, vec![2.0, 7.0, 4.0, 9.0]));
+
+        assert_eq!(
+            m.try_insert_row(3, &[0.0, 0.0]),
+            Err(Error::IndexOutOfBounds { index: 3, bound: 2 })
+        );
+        assert!(m.try_insert_col(0, &[0.0]).is_err());
+        let mut row: Mat = Mat::ones(1, 2);
+        assert_eq!(row.try_remove_row(0), Ok(vec![1.0, 1.0]));
+        assert_eq!(row.shape(), (0, 2));
+    }
+}
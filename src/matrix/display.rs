@@ -0,0 +1,328 @@
+use std::fmt;
+
+use super::Mat;
+use crate::format::pad;
+use crate::scalar::Scalar;
+
+/// Rows or columns beyond this count are elided in [`Style::Plain`] output.
+const MAX_PRINT: usize = 10;
+/// Number of rows or columns kept on each side of an elision.
+const EDGE_ITEMS: usize = 3;
+
+/// Layout used by [`Mat::styled`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Style {
+    /// Column-aligned text, as printed by `{}`.
+    Plain,
+    /// LaTeX `bmatrix` environment.
+    Latex,
+    /// Markdown table with right-aligned columns.
+    Markdown,
+    /// `np.array([[...]])` literal.
+    NumPy,
+    /// Octave/MATLAB `[a, b; c, d]` literal.
+    Octave,
+}
+
+/// A matrix paired with a [`Style`]. Formats with `{}` for fixed-point and
+/// `{:e}` or `{:E}` for scientific notation, honoring precision and width.
+pub struct Styled<'a, T: Scalar> {
+    matrix: &'a Mat<T>,
+    style: Style,
+}
+
+impl<T: Scalar> Mat<T> {
+    pub fn styled(&self, style: Style) -> Styled<'_, T> {
+        Styled {
+            matrix: self,
+            style,
+        }
+    }
+}
+
+/// Plain output is column-aligned; precision and width apply to every entry,
+/// e.g. `{:8.3}`. Matrices with more than ten rows or columns are elided
+/// with `...` unless the alternate flag `{:#}` is given. Every row ends in a
+/// newline.
+impl<T: Scalar + fmt::Display> fmt::Display for Mat<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.styled(Style::Plain), f)
+    }
+}
+
+impl<T: Scalar + fmt::LowerExp> fmt::LowerExp for Mat<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerExp::fmt(&self.styled(Style::Plain), f)
+    }
+}
+
+impl<T: Scalar + fmt::UpperExp> fmt::UpperExp for Mat<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::UpperExp::fmt(&self.styled(Style::Plain), f)
+    }
+}
+
+impl<T: Scalar + fmt::Display> fmt::Display for Styled<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision();
+        self.render(f, |val| match precision {
+            Some(p) => format!("{:.*}", p, val),
+            None => val.to_string(),
+        })
+    }
+}
+
+impl<T: Scalar + fmt::LowerExp> fmt::LowerExp for Styled<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision();
+        self.render(f, |val| match precision {
+            Some(p) => format!("{:.*e}", p, val),
+            None => format!("{:e}", val),
+        })
+    }
+}
+
+impl<T: Scalar + fmt::UpperExp> fmt::UpperExp for Styled<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision();
+        self.render(f, |val| match precision {
+            Some(p) => format!("{:.*E}", p, val),
+            None => format!("{:E}", val),
+        })
+    }
+}
+
+impl<T: Scalar> Styled<'_, T> {
+    fn render(&self, f: &mut fmt::Formatter, cell: impl Fn(&T) -> String) -> fmt::Result {
+        let m = self.matrix;
+        let elide = self.style == Style::Plain && !f.alternate();
+        let rows = shown(m.rows, elide);
+        let cols = shown(m.cols, elide);
+
+        let cells: Vec<Vec<String>> = rows
+            .iter()
+            .map(|&i| {
+                cols.iter()
+                    .map(|&j| match (i, j) {
+                        (Some(i), Some(j)) => literal(cell(&m[(i, j)]), self.style),
+                        _ => "...".to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let min_width = match self.style {
+            Style::Markdown => f.width().unwrap_or(0).max(2),
+            _ => f.width().unwrap_or(0),
+        };
+        let widths: Vec<usize> = (0..cols.len())
+            .map(|j| {
+                cells
+                    .iter()
+                    .map(|row| row[j].chars().count())
+                    .fold(min_width, usize::max)
+            })
+            .collect();
+        let lines: Vec<Vec<String>> = cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(&widths)
+                    .map(|(s, &w)| pad(f, s, w))
+                    .collect()
+            })
+            .collect();
+
+        match self.style {
+            Style::Plain => {
+                for row in &lines {
+                    writeln!(f, "{}", row.join("  "))?;
+                }
+                Ok(())
+            }
+            Style::Latex => {
+                let body: Vec<String> = lines.iter().map(|row| row.join(" & ")).collect();
+                write!(
+                    f,
+                    "\\begin{{bmatrix}}\n{}\n\\end{{bmatrix}}",
+                    body.join(" \\\\\n")
+                )
+            }
+            Style::Markdown => {
+                let header: Vec<String> = widths.iter().map(|&w| " ".repeat(w)).collect();
+                let rule: Vec<String> = widths
+                    .iter()
+                    .map(|&w| format!("{}:", "-".repeat(w - 1)))
+                    .collect();
+                writeln!(f, "| {} |", header.join(" | "))?;
+                write!(f, "| {} |", rule.join(" | "))?;
+                for row in &lines {
+                    write!(f, "\n| {} |", row.join(" | "))?;
+                }
+                Ok(())
+            }
+            Style::NumPy => {
+                let body: Vec<String> = lines
+                    .iter()
+                    .map(|row| format!("[{}]", row.join(", ")))
+                    .collect();
+                write!(f, "np.array([{}])", body.join(",\n          "))
+            }
+            Style::Octave => {
+                let body: Vec<String> = lines.iter().map(|row| row.join(", ")).collect();
+                write!(f, "[{}]", body.join(";\n "))
+            }
+        }
+    }
+}
+
+/// Rewrites a formatted entry so that the NumPy and Octave styles stay
+/// valid code: non-finite values become `np.nan`/`np.inf` or `NaN`/`Inf`,
+/// and `a + bi` becomes `a+bj` or `a+bi`, or `complex(a, b)` when `b` is not
+/// finite.
+fn literal(s: String, style: Style) -> String {
+    let (nan, inf, unit) = match style {
+        Style::NumPy => ("np.nan", "np.inf", "j"),
+        Style::Octave => ("NaN", "Inf", "i"),
+        _ => return s,
+    };
+    let real = |part: &str| match part {
+        "NaN" => nan.to_string(),
+        "inf" => inf.to_string(),
+        "-inf" => format!("-{}", inf),
+        _ => part.to_string(),
+    };
+
+    let Some(body) = s.strip_suffix('i') else {
+        return real(&s);
+    };
+    let Some((re, op, im)) = [" + ", " - "]
+        .into_iter()
+        .find_map(|op| body.split_once(op).map(|(re, im)| (re, op.trim(), im)))
+    else {
+        return real(&s);
+    };
+    if matches!(im, "NaN" | "inf") {
+        let sign = if op == "-" { "-" } else { "" };
+        return format!("complex({}, {}{})", real(re), sign, real(im));
+    }
+    format!("{}{}{}{}", real(re), op, im, unit)
+}
+
+/// Indices to print along an axis of length `len`, with `None` standing for
+/// the elided middle.
+fn shown(len: usize, elide: bool) -> Vec<Option<usize>> {
+    if !elide || len <= MAX_PRINT {
+        return (0..len).map(Some).collect();
+    }
+    (0..EDGE_ITEMS)
+        .map(Some)
+        .chain([None])
+        .chain((len - EDGE_ITEMS..len).map(Some))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex::Complex;
+
+    fn sample() -> Mat {
+        Mat::from_vec(2, 2, vec![1.0, -2.5, 10.0, 0.125])
+    }
+
+    #[test]
+    fn test_display_plain() {
+        let m = sample();
+        assert_eq!(m.to_string(), " 1   -2.5\n10  0.125\n");
+        assert_eq!(format!("{:.2}", m), " 1.00  -2.50\n10.00   0.12\n");
+        assert_eq!(format!("{:6.1}", m), "   1.0    -2.5\n  10.0     0.1\n");
+        assert_eq!(format!("{:<5}", m), "1      -2.5 \n10     0.125\n");
+        assert_eq!(format!("{:.1e}", m), "1.0e0  -2.5e0\n1.0e1  1.2e-1\n");
+        assert_eq!(format!("{:E}", Mat::eye(1) * 1500.0), "1.5E3\n");
+    }
+
+    #[test]
+    fn test_display_elided() {
+        let m = Mat::from_fn(12, 11, |i, j| (i * 11 + j) as f64);
+        let text = m.to_string();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[0], "  0    1    2  ...    8    9   10");
+        assert_eq!(lines[3], "...  ...  ...  ...  ...  ...  ...");
+        assert!(lines[6].ends_with("131"));
+        assert_eq!(format!("{:#}", m).lines().count(), 12);
+    }
+
+    #[test]
+    fn test_display_styles() {
+        let m = sample();
+        assert_eq!(
+            format!("{:.1}", m.styled(Style::Latex)),
+            "\\begin{bmatrix}\n 1.0 & -2.5 \\\\\n10.0 &  0.1\n\\end{bmatrix}"
+        );
+        assert_eq!(
+            m.styled(Style::Markdown).to_string(),
+            "|    |       |\n| -: | ----: |\n|  1 |  -2.5 |\n| 10 | 0.125 |"
+        );
+        assert_eq!(
+            m.styled(Style::NumPy).to_string(),
+            "np.array([[ 1,  -2.5],\n          [10, 0.125]])"
+        );
+        assert_eq!(
+            m.styled(Style::Octave).to_string(),
+            "[ 1,  -2.5;\n 10, 0.125]"
+        );
+        assert_eq!(
+            format!("{:.0e}", Mat::<f64>::eye(2).styled(Style::Octave)),
+            "[1e0, 0e0;\n 0e0, 1e0]"
+        );
+    }
+
+    #[test]
+    fn test_display_numpy_literals() {
+        let m = Mat::from_vec(1, 3, vec![f64::NAN, f64::INFINITY, f64::NEG_INFINITY]);
+        assert_eq!(
+            m.styled(Style::NumPy).to_string(),
+            "np.array([[np.nan, np.inf, -np.inf]])"
+        );
+        let z = Mat::from_vec(
+            1,
+            3,
+            vec![
+                Complex::new(1.0, -1.0),
+                Complex::new(f64::INFINITY, 2.0),
+                Complex::new(0.5, f64::NEG_INFINITY),
+            ],
+        );
+        assert_eq!(
+            format!("{:.1}", z.styled(Style::NumPy)),
+            "np.array([[1.0-1.0j, np.inf+2.0j, complex(0.5, -np.inf)]])"
+        );
+    }
+
+    #[test]
+    fn test_display_octave_literals() {
+        let m = Mat::from_vec(1, 3, vec![f64::NAN, f64::INFINITY, f64::NEG_INFINITY]);
+        assert_eq!(m.styled(Style::Octave).to_string(), "[NaN, Inf, -Inf]");
+        let z = Mat::from_vec(
+            1,
+            3,
+            vec![
+                Complex::new(1.0, -1.0),
+                Complex::new(f64::NAN, 2.0),
+                Complex::new(0.5, f64::NAN),
+            ],
+        );
+        assert_eq!(
+            format!("{:.1}", z.styled(Style::Octave)),
+            "[1.0-1.0i, NaN+2.0i, complex(0.5, NaN)]"
+        );
+    }
+
+    #[test]
+    fn test_display_complex_matrix() {
+        let m = Mat::from_vec(1, 2, vec![Complex::new(1.0, -1.0), Complex::new(0.5, 2.0)]);
+        assert_eq!(format!("{:.1}", m), "1.0 - 1.0i  0.5 + 2.0i\n");
+    }
+}
@@ -0,0 +1,590 @@
+use super::Mat;
+use crate::complex::Complex;
+use crate::Error;
+
+const MAX_SWEEPS: usize = 100;
+const MAX_QR_ITERATIONS: usize = 30;
+const INVERSE_ITERATIONS: usize = 3;
+const INVERSE_ITERATION_SHIFT: f64 = 1e-10;
+const SYMMETRY_TOL: f64 = 1e-10;
+const REPEATED_EIGENVALUE_TOL: f64 = 1e-8;
+
+/// Eigendecomposition `A = V * diag(values) * Vᵀ` of a symmetric matrix.
+///
+/// `values` are sorted in ascending order and column `i` of `vectors` is the
+/// unit eigenvector belonging to `values[i]`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SymmetricEigen {
+    pub values: Vec<f64>,
+    pub vectors: Mat,
+}
+
+impl Mat {
+    pub fn is_symmetric(&self, tol: f64) -> bool {
+        self.rows == self.cols
+            && (0..self.rows).all(|i| (0..i).all(|j| (self[(i, j)] - self[(j, i)]).abs() <= tol))
+    }
+
+    /// Cyclic Jacobi eigenvalue algorithm. The input must be symmetric up to
+    /// a tolerance relative to its largest entry.
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen, Error> {
+        self.check_square()?;
+        let scale = self.data.iter().fold(0.0, |acc: f64, x| acc.max(x.abs()));
+        if !self.is_symmetric(SYMMETRY_TOL * scale.max(1.0)) {
+            return Err(Error::NotSymmetric);
+        }
+
+        let n = self.rows;
+        let mut a = self.clone();
+        let mut v = Mat::eye(n);
+        let norm2: f64 = a.data.iter().map(|x| x * x).sum();
+        let tol = f64::EPSILON * f64::EPSILON * norm2;
+
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            let off: f64 = (0..n)
+                .flat_map(|i| (0..i).map(move |j| (i, j)))
+                .map(|(i, j)| 2.0 * a[(i, j)] * a[(i, j)])
+                .sum();
+            if off <= tol {
+                converged = true;
+                break;
+            }
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    let apq = a[(p, q)];
+                    if apq == 0.0 {
+                        continue;
+                    }
+                    let theta = (a[(q, q)] - a[(p, p)]) / (2.0 * apq);
+                    let t = theta.signum() / (theta.abs() + theta.hypot(1.0));
+                    let c = 1.0 / t.hypot(1.0);
+                    let s = t * c;
+
+                    for k in 0..n {
+                        let (akp, akq) = (a[(k, p)], a[(k, q)]);
+                        a[(k, p)] = c * akp - s * akq;
+                        a[(k, q)] = s * akp + c * akq;
+                    }
+                    for k in 0..n {
+                        let (apk, aqk) = (a[(p, k)], a[(q, k)]);
+                        a[(p, k)] = c * apk - s * aqk;
+                        a[(q, k)] = s * apk + c * aqk;
+                    }
+                    a[(p, q)] = 0.0;
+                    a[(q, p)] = 0.0;
+
+                    for k in 0..n {
+                        let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+                        v[(k, p)] = c * vkp - s * vkq;
+                        v[(k, q)] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+        if !converged {
+            return Err(Error::NoConvergence);
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[(i, i)].total_cmp(&a[(j, j)]));
+
+        let values = order.iter().map(|&i| a[(i, i)]).collect();
+        let mut vectors = Mat::zeros(n, n);
+        for (dst, &src) in order.iter().enumerate() {
+            for k in 0..n {
+                vectors[(k, dst)] = v[(k, src)];
+            }
+        }
+
+        Ok(SymmetricEigen { values, vectors })
+    }
+}
+
+/// Eigenvalues and eigenvectors of a general real matrix.
+///
+/// `values` are sorted by real part, then imaginary part; column `i` of
+/// `vectors` is a unit eigenvector belonging to `values[i]`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Eigen {
+    pub values: Vec<Complex>,
+    pub vectors: Mat<Complex>,
+}
+
+impl Mat {
+    /// Eigenvalues of a square matrix, computed by reducing it to upper
+    /// Hessenberg form and running Francis double-shift QR iterations.
+    /// Complex eigenvalues come in conjugate pairs.
+    pub fn eigenvalues(&self) -> Result<Vec<Complex>, Error> {
+        self.check_square()?;
+
+        let mut values = francis_qr(&self.hessenberg())?;
+        values.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+        Ok(values)
+    }
+
+    /// Eigenvalues together with eigenvectors obtained by inverse iteration.
+    ///
+    /// Eigenvectors of a repeated eigenvalue are kept orthogonal to each
+    /// other, so a diagonalizable matrix always gets a full-rank `vectors`.
+    /// For a defective eigenvalue some of those columns are not eigenvectors.
+    pub fn eigen(&self) -> Result<Eigen, Error> {
+        let values = self.eigenvalues()?;
+        let n = self.rows;
+        let scale = self.data.iter().fold(1.0, |acc: f64, x| acc.max(x.abs()));
+        let mut found: Vec<Vec<Complex>> = Vec::with_capacity(n);
+        for (j, lambda) in values.iter().enumerate() {
+            let same: Vec<&[Complex]> = (0..j)
+                .filter(|&k| (values[k] - *lambda).abs() <= REPEATED_EIGENVALUE_TOL * scale)
+                .map(|k| found[k].as_slice())
+                .collect();
+            found.push(self.inverse_iteration(lambda, &same)?);
+        }
+
+        let mut vectors = Mat::zeros(n, n);
+        for (j, v) in found.into_iter().enumerate() {
+            for (i, z) in v.into_iter().enumerate() {
+                vectors[(i, j)] = z;
+            }
+        }
+        Ok(Eigen { values, vectors })
+    }
+
+    /// Householder reduction to upper Hessenberg form `H = Qᵀ * A * Q`.
+    fn hessenberg(&self) -> Mat {
+        let n = self.rows;
+        let mut h = self.clone();
+
+        for k in 0..n.saturating_sub(2) {
+            let tail_norm: f64 = (k + 2..n).map(|i| h[(i, k)] * h[(i, k)]).sum();
+            if tail_norm == 0.0 {
+                continue;
+            }
+
+            let x0 = h[(k + 1, k)];
+            let norm = (x0 * x0 + tail_norm).sqrt();
+            let alpha = if x0 > 0.0 { -norm } else { norm };
+            let mut v: Vec<f64> = (k + 1..n).map(|i| h[(i, k)]).collect();
+            v[0] -= alpha;
+            let vtv: f64 = v.iter().map(|x| x * x).sum();
+
+            for j in 0..n {
+                let s: f64 = v
+                    .iter()
+                    .enumerate()
+                    .map(|(i, vi)| vi * h[(k + 1 + i, j)])
+                    .sum();
+                let s = 2.0 * s / vtv;
+                for (i, vi) in v.iter().enumerate() {
+                    h[(k + 1 + i, j)] -= s * vi;
+                }
+            }
+            for i in 0..n {
+                let s: f64 = v
+                    .iter()
+                    .enumerate()
+                    .map(|(j, vj)| h[(i, k + 1 + j)] * vj)
+                    .sum();
+                let s = 2.0 * s / vtv;
+                for (j, vj) in v.iter().enumerate() {
+                    h[(i, k + 1 + j)] -= s * vj;
+                }
+            }
+
+            h[(k + 1, k)] = alpha;
+            for i in k + 2..n {
+                h[(i, k)] = 0.0;
+            }
+        }
+        h
+    }
+
+    /// Solves `(A - λI) v = 0` by a few steps of inverse iteration with a
+    /// slightly perturbed shift. A complex shift `a + bi` is handled in real
+    /// arithmetic through the equivalent `2n x 2n` system for `[Re v; Im v]`.
+    /// Each iterate is kept orthogonal to the unit vectors in `found`, which
+    /// belong to the same eigenvalue.
+    fn inverse_iteration(
+        &self,
+        lambda: &Complex,
+        found: &[&[Complex]],
+    ) -> Result<Vec<Complex>, Error> {
+        let n = self.rows;
+        let scale = self.data.iter().fold(1.0, |acc: f64, x| acc.max(x.abs()));
+        let shift = lambda.re + INVERSE_ITERATION_SHIFT * scale;
+        let size = if lambda.im == 0.0 { n } else { 2 * n };
+
+        let mut m = Mat::zeros(size, size);
+        for block in 0..size / n {
+            let o = block * n;
+            for i in 0..n {
+                for j in 0..n {
+                    m[(o + i, o + j)] = self[(i, j)];
+                }
+                m[(o + i, o + i)] -= shift;
+            }
+        }
+        if size == 2 * n {
+            for i in 0..n {
+                m[(i, n + i)] = lambda.im;
+                m[(n + i, i)] = -lambda.im;
+            }
+        }
+
+        // Real images of the found vectors; for a complex eigenvalue `u` and
+        // `i * u` both have to be projected out.
+        let mut basis: Vec<Vec<f64>> = Vec::new();
+        for u in found {
+            if size == n {
+                basis.push(u.iter().map(|z| z.re).collect());
+            } else {
+                basis.push(
+                    u.iter()
+                        .map(|z| z.re)
+                        .chain(u.iter().map(|z| z.im))
+                        .collect(),
+                );
+                basis.push(
+                    u.iter()
+                        .map(|z| -z.im)
+                        .chain(u.iter().map(|z| z.re))
+                        .collect(),
+                );
+            }
+        }
+        let orthogonalize = |x: &mut Mat| {
+            for b in &basis {
+                let d: f64 = x.data.iter().zip(b).map(|(x, b)| x * b).sum();
+                for (x, b) in x.data.iter_mut().zip(b) {
+                    *x -= d * b;
+                }
+            }
+        };
+
+        let lu = m.lu();
+        let mut x = Mat::from_vec(
+            size,
+            1,
+            (0..size)
+                .map(|i| 1.0 + ((i + found.len()) % size) as f64 / size as f64)
+                .collect(),
+        );
+        orthogonalize(&mut x);
+        for _ in 0..INVERSE_ITERATIONS {
+            x = lu.solve(&x)?;
+            orthogonalize(&mut x);
+            let norm = x.data.iter().map(|v| v * v).sum::<f64>().sqrt();
+            for v in x.data.iter_mut() {
+                *v /= norm;
+            }
+        }
+
+        Ok((0..n)
+            .map(|i| {
+                let im = if size == n { 0.0 } else { x[(n + i, 0)] };
+                Complex::new(x[(i, 0)], im)
+            })
+            .collect())
+    }
+}
+
+/// Eigenvalues of an upper Hessenberg matrix by Francis double-shift QR.
+///
+/// Each sweep chases a 3 x 3 bulge down the active window `lo..hi`, using the
+/// eigenvalues of the trailing 2 x 2 block as an implicit pair of shifts.
+/// Negligible subdiagonal entries split the problem, and 1 x 1 or 2 x 2
+/// blocks at the bottom are read off directly.
+fn francis_qr(h: &Mat) -> Result<Vec<Complex>, Error> {
+    let n = h.rows;
+    let mut h = h.clone();
+    let norm = h.data.iter().map(|x| x.abs()).sum::<f64>();
+    let mut values = Vec::with_capacity(n);
+
+    let mut hi = n;
+    let mut iterations = 0;
+    while hi > 0 {
+        let mut lo = hi - 1;
+        while lo > 0 {
+            let mut s = h[(lo - 1, lo - 1)].abs() + h[(lo, lo)].abs();
+            if s == 0.0 {
+                s = norm;
+            }
+            if h[(lo, lo - 1)].abs() <= f64::EPSILON * s {
+                h[(lo, lo - 1)] = 0.0;
+                break;
+            }
+            lo -= 1;
+        }
+
+        match hi - lo {
+            1 => {
+                values.push(Complex::new(h[(lo, lo)], 0.0));
+                hi -= 1;
+                iterations = 0;
+                continue;
+            }
+            2 => {
+                let (a, b) = (h[(lo, lo)], h[(lo, lo + 1)]);
+                let (c, d) = (h[(lo + 1, lo)], h[(lo + 1, lo + 1)]);
+                let (z1, z2) = eigenvalues_2x2(a, b, c, d);
+                values.push(z1);
+                values.push(z2);
+                hi -= 2;
+                iterations = 0;
+                continue;
+            }
+            _ => {}
+        }
+
+        if iterations == MAX_QR_ITERATIONS {
+            return Err(Error::NoConvergence);
+        }
+        iterations += 1;
+
+        // Sum and product of the two shifts. Every tenth iteration uses an
+        // ad hoc exceptional shift to break out of cycles.
+        let (a, b) = (h[(hi - 2, hi - 2)], h[(hi - 2, hi - 1)]);
+        let (c, d) = (h[(hi - 1, hi - 2)], h[(hi - 1, hi - 1)]);
+        let (sum, product) = if iterations % 10 == 0 {
+            let w = c.abs() + h[(hi - 2, hi - 3)].abs();
+            let x = d + 0.75 * w;
+            (2.0 * x, x * x - 0.4375 * w * w)
+        } else {
+            (a + d, a * d - b * c)
+        };
+
+        // First column of (H - σ₁I)(H - σ₂I), which has only three non-zeros.
+        let (h00, h01, h10) = (h[(lo, lo)], h[(lo, lo + 1)], h[(lo + 1, lo)]);
+        let mut x = h00 * h00 + h01 * h10 - sum * h00 + product;
+        let mut y = h10 * (h00 + h[(lo + 1, lo + 1)] - sum);
+        let mut z = h10 * h[(lo + 2, lo + 1)];
+
+        for k in lo..hi - 1 {
+            let len = if k + 2 < hi { 3 } else { 2 };
+            let v = [x, y, z];
+            reflect(&mut h, &v[..len], k, lo, hi);
+            if k > lo {
+                for i in k + 1..k + len {
+                    h[(i, k - 1)] = 0.0;
+                }
+            }
+            if k + 2 < hi {
+                x = h[(k + 1, k)];
+                y = h[(k + 2, k)];
+                z = if k + 3 < hi { h[(k + 3, k)] } else { 0.0 };
+            }
+        }
+    }
+
+    Ok(values)
+}
+
+/// Applies the Householder reflector that maps `x` onto a multiple of the
+/// first unit vector to rows and columns `k..k + x.len()` of the active
+/// window `lo..hi` of `h`, from both sides.
+fn reflect(h: &mut Mat, x: &[f64], k: usize, lo: usize, hi: usize) {
+    let norm = x.iter().map(|v| v * v).sum::<f64>().sqrt();
+    if norm == 0.0 {
+        return;
+    }
+    let mut v = x.to_vec();
+    v[0] += norm.copysign(x[0]);
+    let beta = 2.0 / v.iter().map(|v| v * v).sum::<f64>();
+
+    for j in k.saturating_sub(1).max(lo)..hi {
+        let s = beta
+            * v.iter()
+                .enumerate()
+                .map(|(i, vi)| vi * h[(k + i, j)])
+                .sum::<f64>();
+        for (i, vi) in v.iter().enumerate() {
+            h[(k + i, j)] -= s * vi;
+        }
+    }
+    for i in lo..(k + v.len() + 1).min(hi) {
+        let s = beta
+            * v.iter()
+                .enumerate()
+                .map(|(j, vj)| h[(i, k + j)] * vj)
+                .sum::<f64>();
+        for (j, vj) in v.iter().enumerate() {
+            h[(i, k + j)] -= s * vj;
+        }
+    }
+}
+
+/// Eigenvalues of `[a b; c d]`, with the real case arranged to avoid
+/// cancellation.
+fn eigenvalues_2x2(a: f64, b: f64, c: f64, d: f64) -> (Complex, Complex) {
+    let p = 0.5 * (a - d);
+    let q = p * p + b * c;
+    if q < 0.0 {
+        let mid = d + p;
+        let im = (-q).sqrt();
+        return (Complex::new(mid, im), Complex::new(mid, -im));
+    }
+    let r = p + q.sqrt().copysign(p);
+    if r == 0.0 {
+        return (Complex::new(d, 0.0), Complex::new(d, 0.0));
+    }
+    (Complex::new(d + r, 0.0), Complex::new(d - b * c / r, 0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_mat_approx_eq;
+
+    #[test]
+    fn test_symmetric_eigen_2x2() {
+        let m = Mat::from_vec(2, 2, vec![2.0, 1.0, 1.0, 2.0]);
+        let eig = m.symmetric_eigen().unwrap();
+        assert!((eig.values[0] - 1.0).abs() < 1e-12);
+        assert!((eig.values[1] - 3.0).abs() < 1e-12);
+        let v = &eig.vectors;
+        assert!((v[(0, 1)].abs() - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-12);
+        assert!((v[(0, 1)] - v[(1, 1)]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_symmetric_eigen_reconstruct() {
+        let m = Mat::from_vec(
+            4,
+            4,
+            vec![
+                4.0, 1.0, -2.0, 2.0, 1.0, 2.0, 0.0, 1.0, -2.0, 0.0, 3.0, -2.0, 2.0, 1.0, -2.0, -1.0,
+            ],
+        );
+        let eig = m.symmetric_eigen().unwrap();
+        assert!(eig.values.windows(2).all(|w| w[0] <= w[1]));
+
+        let v = &eig.vectors;
+        assert_mat_approx_eq!(v.transpose().dot(v), Mat::eye(4), 1e-12);
+
+        let mut lambda = Mat::zeros(4, 4);
+        for i in 0..4 {
+            lambda[(i, i)] = eig.values[i];
+        }
+        assert_mat_approx_eq!(v.dot(&lambda).dot(&v.transpose()), m, 1e-10);
+        assert!((eig.values.iter().sum::<f64>() - m.trace()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_symmetric_eigen_rejects_non_symmetric() {
+        let m = Mat::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(m.symmetric_eigen(), Err(Error::NotSymmetric));
+        assert!(!m.is_symmetric(0.5));
+        assert!(m.is_symmetric(1.0));
+    }
+
+    fn assert_eigenpair(m: &Mat, lambda: &Complex, v: &[Complex]) {
+        let n = m.rows;
+        let norm = v.iter().map(|z| z.abs() * z.abs()).sum::<f64>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-10);
+        for i in 0..n {
+            let mut re = -(lambda.re * v[i].re - lambda.im * v[i].im);
+            let mut im = -(lambda.re * v[i].im + lambda.im * v[i].re);
+            for j in 0..n {
+                re += m[(i, j)] * v[j].re;
+                im += m[(i, j)] * v[j].im;
+            }
+            assert!(re.abs() < 1e-8 && im.abs() < 1e-8, "residual {} {}", re, im);
+        }
+    }
+
+    #[test]
+    fn test_eigenvalues_rotation() {
+        let m = Mat::from_vec(2, 2, vec![0.0, -1.0, 1.0, 0.0]);
+        let values = m.eigenvalues().unwrap();
+        assert_eq!(values.len(), 2);
+        assert!(values[0].re.abs() < 1e-12 && (values[0].im + 1.0).abs() < 1e-12);
+        assert!(values[1].re.abs() < 1e-12 && (values[1].im - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_eigenvalues_real() {
+        let m = Mat::from_vec(3, 3, vec![2.0, 0.0, 0.0, 1.0, 3.0, 0.0, 4.0, 5.0, -1.0]);
+        let values = m.eigenvalues().unwrap();
+        let expected = [-1.0, 2.0, 3.0];
+        for (z, e) in values.iter().zip(expected) {
+            assert!((z.re - e).abs() < 1e-10 && z.im.abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_eigenvalues_companion() {
+        // Companion matrix of (x - 1)(x² + 2x + 5), roots 1 and -1 ± 2i.
+        let m = Mat::from_vec(
+            4,
+            4,
+            vec![
+                -1.0, -3.0, 5.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0,
+            ],
+        );
+        let values = m.eigenvalues().unwrap();
+        let expected = [(-1.0, -2.0), (-1.0, 2.0), (0.0, 0.0), (1.0, 0.0)];
+        for (z, (re, im)) in values.iter().zip(expected) {
+            assert!((z.re - re).abs() < 1e-10 && (z.im - im).abs() < 1e-10);
+        }
+        let product = values
+            .iter()
+            .fold(Complex::new(1.0, 0.0), |acc, z| acc * *z);
+        assert!((product.re - m.det()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_eigenvalues_cyclic_permutation() {
+        // Plain shifts stall on a cyclic shift; the exceptional shift does not.
+        let m = Mat::from_fn(5, 5, |i, j| if (i + 1) % 5 == j { 1.0 } else { 0.0 });
+        let values = m.eigenvalues().unwrap();
+        for root in Complex::roots_of_unity(5) {
+            assert!(values.iter().any(|z| (*z - root).abs() < 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_eigen_repeated_eigenvalue() {
+        let rotation = Mat::from_vec(2, 2, vec![0.0, -1.0, 1.0, 0.0]);
+        let cases = [
+            Mat::from_vec(3, 3, vec![2.0, 0.0, 1.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0]),
+            Mat::block_diag(&[&rotation, &rotation]),
+        ];
+        for m in cases {
+            let n = m.rows;
+            let eig = m.eigen().unwrap();
+            for (j, lambda) in eig.values.iter().enumerate() {
+                let v: Vec<Complex> = (0..n).map(|i| eig.vectors[(i, j)]).collect();
+                assert_eigenpair(&m, lambda, &v);
+            }
+            // Full complex rank of V is full real rank of [Re V, -Im V; Im V, Re V].
+            let embedding = Mat::from_fn(2 * n, 2 * n, |i, j| {
+                let z = eig.vectors[(i % n, j % n)];
+                match (i < n, j < n) {
+                    (true, true) | (false, false) => z.re,
+                    (true, false) => -z.im,
+                    (false, true) => z.im,
+                }
+            });
+            assert_eq!(embedding.rank(1e-8), 2 * n);
+        }
+    }
+
+    #[test]
+    fn test_eigen_vectors() {
+        let m = Mat::from_vec(
+            4,
+            4,
+            vec![
+                1.0, 2.0, 0.0, -1.0, -3.0, 1.0, 4.0, 0.0, 0.5, 0.0, 2.0, 1.0, 1.0, -2.0, 0.0, 3.0,
+            ],
+        );
+        let eig = m.eigen().unwrap();
+        let trace: f64 = eig.values.iter().map(|z| z.re).sum();
+        assert!((trace - m.trace()).abs() < 1e-10);
+        for (j, lambda) in eig.values.iter().enumerate() {
+            let v: Vec<Complex> = (0..4).map(|i| eig.vectors[(i, j)]).collect();
+            assert_eigenpair(&m, lambda, &v);
+        }
+    }
+}
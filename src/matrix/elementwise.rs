@@ -0,0 +1,293 @@
+use super::Mat;
+use crate::scalar::Scalar;
+use crate::Error;
+
+/// Shape of the result of combining `left` and `right` element by element.
+///
+/// Follows NumPy: along each axis the sizes must match, or one of them must
+/// be 1, in which case that operand is repeated along the axis. A `1 x n` row
+/// can therefore be added to every row of an `m x n` matrix, and an `m x 1`
+/// column to every column.
+pub fn broadcast_shape(
+    left: (usize, usize),
+    right: (usize, usize),
+) -> Result<(usize, usize), Error> {
+    let axis = |a: usize, b: usize| match (a, b) {
+        _ if a == b => Some(a),
+        (1, _) => Some(b),
+        (_, 1) => Some(a),
+        _ => None,
+    };
+    match (axis(left.0, right.0), axis(left.1, right.1)) {
+        (Some(rows), Some(cols)) => Ok((rows, cols)),
+        _ => Err(Error::ShapeMismatch { left, right }),
+    }
+}
+
+impl<T: Scalar> Mat<T> {
+    pub fn map<U: Scalar>(&self, f: impl Fn(T) -> U) -> Mat<U> {
+        Mat {
+            data: self.data.iter().map(|&val| f(val)).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    pub fn map_inplace(&mut self, f: impl Fn(T) -> T) {
+        for val in self.data.iter_mut() {
+            *val = f(*val);
+        }
+    }
+
+    /// Combines two matrices element by element, broadcasting `1 x n` and
+    /// `m x 1` operands.
+    pub fn zip_map<U: Scalar, V: Scalar>(&self, other: &Mat<U>, f: impl Fn(T, U) -> V) -> Mat<V> {
+        self.try_zip_map(other, f)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_zip_map<U: Scalar, V: Scalar>(
+        &self,
+        other: &Mat<U>,
+        f: impl Fn(T, U) -> V,
+    ) -> Result<Mat<V>, Error> {
+        let (rows, cols) = broadcast_shape(self.shape(), other.shape())?;
+        if self.shape() == other.shape() {
+            let data = self
+                .data
+                .iter()
+                .zip(&other.data)
+                .map(|(&a, &b)| f(a, b))
+                .collect();
+            return Ok(Mat { data, rows, cols });
+        }
+
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                data.push(f(self.broadcast_get(i, j), other.broadcast_get(i, j)));
+            }
+        }
+        Ok(Mat { data, rows, cols })
+    }
+
+    /// Updates `self` in place with `f(self, other)`; `other` must broadcast
+    /// to the shape of `self`.
+    pub(crate) fn zip_assign<U: Scalar>(
+        &mut self,
+        other: &Mat<U>,
+        f: impl Fn(T, U) -> T,
+    ) -> Result<(), Error> {
+        if !self.broadcasts_from(other.shape()) {
+            return Err(Error::ShapeMismatch {
+                left: self.shape(),
+                right: other.shape(),
+            });
+        }
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                let val = &mut self.data[i * self.cols + j];
+                *val = f(*val, other.broadcast_get(i, j));
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether an operand of the given shape broadcasts to exactly the shape
+    /// of `self`.
+    pub(crate) fn broadcasts_from(&self, shape: (usize, usize)) -> bool {
+        broadcast_shape(self.shape(), shape) == Ok(self.shape())
+    }
+
+    fn broadcast_get(&self, row: usize, col: usize) -> T {
+        let i = if self.rows == 1 { 0 } else { row };
+        let j = if self.cols == 1 { 0 } else { col };
+        self.data[i * self.cols + j]
+    }
+
+    /// Element-wise (Hadamard) product. It broadcasts `1 x n` and `m x 1`
+    /// operands like `+` and `-`, whereas `*` is the matrix product.
+    pub fn hadamard(&self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a * b)
+    }
+
+    pub fn try_hadamard(&self, other: &Self) -> Result<Self, Error> {
+        self.try_zip_map(other, |a, b| a * b)
+    }
+
+    /// In-place [`Mat::hadamard`]; `other` must broadcast to the shape of
+    /// `self`, as for `+=`.
+    pub fn hadamard_assign(&mut self, other: &Self) {
+        self.try_hadamard_assign(other)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_hadamard_assign(&mut self, other: &Self) -> Result<(), Error> {
+        self.zip_assign(other, |a, b| a * b)
+    }
+
+    /// Element-wise quotient, broadcasting like [`Mat::hadamard`].
+    pub fn elem_div(&self, other: &Self) -> Self {
+        self.zip_map(other, |a, b| a / b)
+    }
+
+    pub fn try_elem_div(&self, other: &Self) -> Result<Self, Error> {
+        self.try_zip_map(other, |a, b| a / b)
+    }
+}
+
+impl Mat {
+    pub fn abs(&self) -> Self {
+        self.map(f64::abs)
+    }
+
+    pub fn exp(&self) -> Self {
+        self.map(f64::exp)
+    }
+
+    pub fn ln(&self) -> Self {
+        self.map(f64::ln)
+    }
+
+    pub fn sqrt(&self) -> Self {
+        self.map(f64::sqrt)
+    }
+
+    /// Raises every entry to the real power `n`. See [`Mat::powm`] for the
+    /// matrix power.
+    pub fn elem_powf(&self, n: f64) -> Self {
+        self.map(|val| val.powf(n))
+    }
+
+    /// Raises every entry to the integer power `n`. See [`Mat::pow`] for the
+    /// matrix power.
+    pub fn elem_powi(&self, n: i32) -> Self {
+        self.map(|val| val.powi(n))
+    }
+
+    /// Element-wise power with exponents taken from `other`, broadcasting.
+    pub fn elem_pow(&self, other: &Self) -> Self {
+        self.zip_map(other, f64::powf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_broadcast_shape() {
+        assert_eq!(broadcast_shape((3, 4), (3, 4)), Ok((3, 4)));
+        assert_eq!(broadcast_shape((3, 4), (1, 4)), Ok((3, 4)));
+        assert_eq!(broadcast_shape((3, 1), (3, 4)), Ok((3, 4)));
+        assert_eq!(broadcast_shape((3, 1), (1, 4)), Ok((3, 4)));
+        assert_eq!(broadcast_shape((1, 1), (2, 5)), Ok((2, 5)));
+        assert_eq!(
+            broadcast_shape((3, 4), (2, 4)),
+            Err(Error::ShapeMismatch {
+                left: (3, 4),
+                right: (2, 4)
+            })
+        );
+    }
+
+    #[test]
+    fn test_map() {
+        let m = Mat::from_vec(2, 2, vec![1.0, -2.0, 3.0, -4.0]);
+        assert_eq!(m.map(|x| x * 10.0).data, vec![10.0, -20.0, 30.0, -40.0]);
+        assert_eq!(m.map(|x| x as i64).data, vec![1, -2, 3, -4]);
+
+        let mut n = m.clone();
+        n.map_inplace(|x| x + 1.0);
+        assert_eq!(n.data, vec![2.0, -1.0, 4.0, -3.0]);
+    }
+
+    #[test]
+    fn test_zip_map() {
+        let a = Mat::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b = Mat::from_vec(2, 2, vec![1_u8, 0, 0, 1]);
+        let picked = a.zip_map(&b, |x, keep| if keep == 1 { x } else { 0.0 });
+        assert_eq!(picked.data, vec![1.0, 0.0, 0.0, 4.0]);
+    }
+
+    #[test]
+    fn test_hadamard_and_div() {
+        let a = Mat::from_vec(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b = Mat::from_vec(2, 2, vec![2.0, 4.0, 6.0, 8.0]);
+        assert_eq!(a.hadamard(&b).data, vec![2.0, 8.0, 18.0, 32.0]);
+        assert_eq!(b.elem_div(&a).data, vec![2.0; 4]);
+        assert!(a.try_hadamard(&Mat::ones(3, 3)).is_err());
+    }
+
+    #[test]
+    fn test_elementwise_functions() {
+        let m = Mat::from_vec(1, 3, vec![1.0, 4.0, 9.0]);
+        assert_eq!(m.sqrt().data, vec![1.0, 2.0, 3.0]);
+        assert_eq!(m.elem_powi(2).data, vec![1.0, 16.0, 81.0]);
+        assert_eq!(m.elem_powf(0.5).data, vec![1.0, 2.0, 3.0]);
+        assert_eq!((-&m).abs(), m);
+        assert_eq!(Mat::zeros(1, 2).exp().data, vec![1.0, 1.0]);
+        assert!((m.ln().exp()[(0, 2)] - 9.0).abs() < 1e-12);
+
+        let exponents = Mat::from_vec(1, 3, vec![0.0, 1.0, 2.0]);
+        assert_eq!(m.elem_pow(&exponents).data, vec![1.0, 4.0, 81.0]);
+    }
+
+    #[test]
+    fn test_broadcasting_operators() {
+        let m = Mat::from_vec(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let row = Mat::from_vec(1, 3, vec![10.0, 20.0, 30.0]);
+        let col = Mat::from_vec(2, 1, vec![100.0, 200.0]);
+
+        assert_eq!((&m + &row).data, vec![11.0, 22.0, 33.0, 14.0, 25.0, 36.0]);
+        assert_eq!(
+            (&m - &col).data,
+            vec![-99.0, -98.0, -97.0, -196.0, -195.0, -194.0]
+        );
+        assert_eq!(
+            (&row + &col).data,
+            vec![110.0, 120.0, 130.0, 210.0, 220.0, 230.0]
+        );
+        assert_eq!(
+            (&row - m.clone()).data,
+            vec![9.0, 18.0, 27.0, 6.0, 15.0, 24.0]
+        );
+        assert_eq!((row.clone() + &m).shape(), (2, 3));
+        assert_eq!(
+            m.hadamard(&col).data,
+            vec![100.0, 200.0, 300.0, 800.0, 1000.0, 1200.0]
+        );
+        assert_eq!(
+            row.hadamard(&m).data,
+            vec![10.0, 40.0, 90.0, 40.0, 100.0, 180.0]
+        );
+        assert_eq!(
+            row.hadamard(&col).data,
+            vec![1000.0, 2000.0, 3000.0, 2000.0, 4000.0, 6000.0]
+        );
+        assert_eq!(m.elem_div(&row).data[3..], [0.4, 0.25, 0.2]);
+
+        let mut scaled = m.clone();
+        scaled.hadamard_assign(&col);
+        assert_eq!(scaled, m.hadamard(&col));
+        assert_eq!(
+            row.clone().try_hadamard_assign(&m),
+            Err(Error::ShapeMismatch {
+                left: (1, 3),
+                right: (2, 3)
+            })
+        );
+
+        let mut acc = m.clone();
+        acc += &row;
+        acc -= col.clone();
+        assert_eq!(acc.data, vec![-89.0, -78.0, -67.0, -186.0, -175.0, -164.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix shapes 1x3 and 2x3 are not compatible.")]
+    fn test_add_assign_cannot_grow() {
+        let mut row: Mat = Mat::ones(1, 3);
+        row += &Mat::ones(2, 3);
+    }
+}
@@ -0,0 +1,143 @@
+use super::Mat;
+use crate::scalar::Scalar;
+
+/// Edge length of the square tiles the product is computed in. Three
+/// `BLOCK x BLOCK` tiles of `f64` fit comfortably in a typical L2 cache.
+const BLOCK: usize = 64;
+
+/// Minimum number of multiply-adds before the work is split across threads.
+#[cfg(feature = "parallel")]
+const PARALLEL_THRESHOLD: usize = 1 << 18;
+
+/// Matrix product `a * b`. `b` is packed in transposed form so that both
+/// operands are read along contiguous rows, and the loops are tiled to keep
+/// the working set in cache. With the `parallel` feature, large products are
+/// split by rows of the result across threads.
+pub(crate) fn gemm<T: Scalar>(a: &Mat<T>, b: &Mat<T>) -> Mat<T> {
+    #[cfg(feature = "parallel")]
+    if a.rows * a.cols * b.cols >= PARALLEL_THRESHOLD {
+        return gemm_parallel(a, b);
+    }
+    gemm_serial(a, b)
+}
+
+/// [`gemm`] on the calling thread only, whatever the size.
+pub(crate) fn gemm_serial<T: Scalar>(a: &Mat<T>, b: &Mat<T>) -> Mat<T> {
+    let (m, k, n) = (a.rows, a.cols, b.cols);
+    let mut c = vec![T::zero(); m * n];
+    if m > 0 && n > 0 && k > 0 {
+        kernel(&a.data, &b.transpose().data, &mut c, k, n);
+    }
+    Mat {
+        data: c,
+        rows: m,
+        cols: n,
+    }
+}
+
+#[cfg(feature = "parallel")]
+fn gemm_parallel<T: Scalar>(a: &Mat<T>, b: &Mat<T>) -> Mat<T> {
+    let (m, k, n) = (a.rows, a.cols, b.cols);
+    let mut c = vec![T::zero(); m * n];
+    let bt = b.transpose();
+    let threads = std::thread::available_parallelism().map_or(1, |t| t.get());
+    let rows_per_thread = m.div_ceil(threads);
+    std::thread::scope(|s| {
+        for (chunk, c_rows) in c.chunks_mut(rows_per_thread * n).enumerate() {
+            let start = chunk * rows_per_thread * k;
+            let a_rows = &a.data[start..start + c_rows.len() / n * k];
+            let bt = &bt.data;
+            s.spawn(move || kernel(a_rows, bt, c_rows, k, n));
+        }
+    });
+    Mat {
+        data: c,
+        rows: m,
+        cols: n,
+    }
+}
+
+/// Accumulates `a * btᵀ` into `c`, where `a` holds `c.len() / n` rows of
+/// length `k` and `bt` holds `n` rows of length `k`.
+fn kernel<T: Scalar>(a: &[T], bt: &[T], c: &mut [T], k: usize, n: usize) {
+    let m = c.len() / n;
+    for i0 in (0..m).step_by(BLOCK) {
+        for j0 in (0..n).step_by(BLOCK) {
+            for p0 in (0..k).step_by(BLOCK) {
+                let p1 = (p0 + BLOCK).min(k);
+                for i in i0..(i0 + BLOCK).min(m) {
+                    let a_row = &a[i * k + p0..i * k + p1];
+                    for j in j0..(j0 + BLOCK).min(n) {
+                        let b_row = &bt[j * k + p0..j * k + p1];
+                        c[i * n + j] = c[i * n + j] + dot(a_row, b_row);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Dot product with four independent accumulators, which lets the compiler
+/// keep several multiply-adds in flight.
+fn dot<T: Scalar>(x: &[T], y: &[T]) -> T {
+    let mut acc = [T::zero(); 4];
+    let chunks = x.len() / 4 * 4;
+    for (xs, ys) in x[..chunks].chunks_exact(4).zip(y[..chunks].chunks_exact(4)) {
+        for l in 0..4 {
+            acc[l] = acc[l] + xs[l] * ys[l];
+        }
+    }
+    let mut sum = (acc[0] + acc[1]) + (acc[2] + acc[3]);
+    for (&xv, &yv) in x[chunks..].iter().zip(&y[chunks..]) {
+        sum = sum + xv * yv;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive(a: &Mat<i64>, b: &Mat<i64>) -> Mat<i64> {
+        let mut c = Mat::zeros(a.rows, b.cols);
+        for i in 0..a.rows {
+            for j in 0..b.cols {
+                for p in 0..a.cols {
+                    c[(i, j)] += a[(i, p)] * b[(p, j)];
+                }
+            }
+        }
+        c
+    }
+
+    fn sample(rows: usize, cols: usize, seed: i64) -> Mat<i64> {
+        let data = (0..rows * cols)
+            .map(|x| (x as i64 * 7919 + seed) % 23 - 11)
+            .collect();
+        Mat::from_vec(rows, cols, data)
+    }
+
+    #[test]
+    fn test_gemm_matches_naive() {
+        for &(m, k, n) in &[(1, 1, 1), (3, 5, 2), (70, 130, 65), (129, 64, 200)] {
+            let a = sample(m, k, 1);
+            let b = sample(k, n, 5);
+            assert_eq!(gemm(&a, &b), naive(&a, &b));
+            assert_eq!(gemm_serial(&a, &b), naive(&a, &b));
+        }
+    }
+
+    #[test]
+    fn test_gemm_f64() {
+        let a = Mat::from_vec(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let b = Mat::from_vec(3, 2, vec![7.0, 8.0, 9.0, 10.0, 11.0, 12.0]);
+        assert_eq!(gemm(&a, &b).data, vec![58.0, 64.0, 139.0, 154.0]);
+    }
+
+    #[test]
+    fn test_gemm_empty_inner_dimension() {
+        let a: Mat = Mat::from_vec(3, 0, vec![]);
+        let b = Mat::from_vec(0, 4, vec![]);
+        assert_eq!(a.dot(&b), Mat::zeros(3, 4));
+    }
+}
@@ -0,0 +1,186 @@
+use std::slice;
+use std::vec;
+
+use super::{Mat, MatView};
+use crate::scalar::Scalar;
+use crate::Error;
+
+impl<T: Scalar> Mat<T> {
+    /// Entries in row-major order.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// Entries in row-major order together with their `(row, col)` position.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let cols = self.cols;
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(k, val)| ((k / cols, k % cols), val))
+    }
+
+    pub fn indexed_iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        let cols = self.cols;
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(move |(k, val)| ((k / cols, k % cols), val))
+    }
+
+    /// Rows as `1 x cols` views.
+    pub fn rows(&self) -> impl Iterator<Item = MatView<'_, T>> {
+        (0..self.rows).map(move |i| self.row(i))
+    }
+
+    /// Columns as `rows x 1` views.
+    pub fn cols(&self) -> impl Iterator<Item = MatView<'_, T>> {
+        (0..self.cols).map(move |j| self.col(j))
+    }
+
+    /// Collects `iter` in row-major order into a `rows x cols` matrix.
+    pub fn from_iter_with_shape(
+        rows: usize,
+        cols: usize,
+        iter: impl IntoIterator<Item = T>,
+    ) -> Self {
+        Self::try_from_iter_with_shape(rows, cols, iter).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_from_iter_with_shape(
+        rows: usize,
+        cols: usize,
+        iter: impl IntoIterator<Item = T>,
+    ) -> Result<Self, Error> {
+        let data: Vec<T> = iter.into_iter().collect();
+        if rows * cols != data.len() {
+            return Err(Error::LengthMismatch {
+                expected: rows * cols,
+                actual: data.len(),
+            });
+        }
+        Ok(Self { data, rows, cols })
+    }
+}
+
+/// Collects into a column vector; use [`Mat::reshape`] or
+/// [`Mat::from_iter_with_shape`] for any other shape.
+impl<T: Scalar> FromIterator<T> for Mat<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let data: Vec<T> = iter.into_iter().collect();
+        let rows = data.len();
+        Self {
+            data,
+            rows,
+            cols: 1,
+        }
+    }
+}
+
+impl<T: Scalar> IntoIterator for Mat<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T: Scalar> IntoIterator for &'a Mat<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<'a, T: Scalar> IntoIterator for &'a mut Mat<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Mat {
+        Mat::from_vec(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut m = sample();
+        assert_eq!(m.iter().sum::<f64>(), 21.0);
+        for val in m.iter_mut() {
+            *val *= 2.0;
+        }
+        for val in &mut m {
+            *val += 1.0;
+        }
+        assert_eq!(
+            (&m).into_iter().copied().collect::<Vec<_>>(),
+            vec![3.0, 5.0, 7.0, 9.0, 11.0, 13.0]
+        );
+        assert_eq!(m.into_iter().last(), Some(13.0));
+    }
+
+    #[test]
+    fn test_indexed_iter() {
+        let mut m = sample();
+        let positions: Vec<_> = m.indexed_iter().map(|(pos, _)| pos).collect();
+        assert_eq!(
+            positions,
+            vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]
+        );
+        assert_eq!(m.indexed_iter().nth(4), Some(((1, 1), &5.0)));
+
+        for ((i, j), val) in m.indexed_iter_mut() {
+            if i == j {
+                *val = 0.0;
+            }
+        }
+        assert_eq!(m.data, vec![0.0, 2.0, 3.0, 4.0, 0.0, 6.0]);
+    }
+
+    #[test]
+    fn test_rows_cols() {
+        let m = sample();
+        let row_sums: Vec<f64> = m.rows().map(|row| row.iter().sum()).collect();
+        assert_eq!(row_sums, vec![6.0, 15.0]);
+        let col_max: Vec<f64> = m
+            .cols()
+            .map(|col| col.iter().fold(f64::MIN, |acc, &x| acc.max(x)))
+            .collect();
+        assert_eq!(col_max, vec![4.0, 5.0, 6.0]);
+        assert_eq!(m.rows().count(), 2);
+        assert_eq!(
+            m.t().iter().copied().collect::<Vec<_>>(),
+            m.transpose().data
+        );
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let v: Mat = (1..=3).map(f64::from).collect();
+        assert_eq!(v, Mat::from_vec(3, 1, vec![1.0, 2.0, 3.0]));
+
+        let m = Mat::from_iter_with_shape(2, 3, sample().iter().map(|x| x * 10.0));
+        assert_eq!(m, sample() * 10.0);
+        assert_eq!(
+            Mat::try_from_iter_with_shape(2, 2, vec![1.0; 3]),
+            Err(Error::LengthMismatch {
+                expected: 4,
+                actual: 3
+            })
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use super::Mat;
+use crate::Error;
+
+/// LU factorization with partial pivoting, `P * A = L * U`.
+///
+/// `L` (unit diagonal, stored below the diagonal) and `U` share a single
+/// matrix; `perm[i]` is the row of `A` that ended up in row `i`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Lu {
+    lu: Mat,
+    perm: Vec<usize>,
+    sign: f64,
+}
+
+impl Mat {
+    pub fn lu(&self) -> Lu {
+        self.try_lu().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_lu(&self) -> Result<Lu, Error> {
+        self.check_square()?;
+
+        let n = self.rows;
+        let mut lu = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut sign = 1.0;
+
+        for k in 0..n {
+            let pivot_row = (k..n)
+                .max_by(|&a, &b| lu[(a, k)].abs().total_cmp(&lu[(b, k)].abs()))
+                .unwrap();
+            if pivot_row != k {
+                lu.swap_rows(k, pivot_row);
+                perm.swap(k, pivot_row);
+                sign = -sign;
+            }
+
+            let pivot = lu[(k, k)];
+            if pivot == 0.0 {
+                continue;
+            }
+            for i in k + 1..n {
+                let factor = lu[(i, k)] / pivot;
+                lu[(i, k)] = factor;
+                for j in k + 1..n {
+                    lu[(i, j)] -= factor * lu[(k, j)];
+                }
+            }
+        }
+
+        Ok(Lu { lu, perm, sign })
+    }
+}
+
+impl Lu {
+    pub fn size(&self) -> usize {
+        self.lu.rows
+    }
+
+    pub fn l(&self) -> Mat {
+        let n = self.size();
+        let mut l = Mat::eye(n);
+        for i in 1..n {
+            for j in 0..i {
+                l[(i, j)] = self.lu[(i, j)];
+            }
+        }
+        l
+    }
+
+    pub fn u(&self) -> Mat {
+        let n = self.size();
+        let mut u = Mat::zeros(n, n);
+        for i in 0..n {
+            for j in i..n {
+                u[(i, j)] = self.lu[(i, j)];
+            }
+        }
+        u
+    }
+
+    pub fn p(&self) -> Mat {
+        let n = self.size();
+        let mut p = Mat::zeros(n, n);
+        for (i, &j) in self.perm.iter().enumerate() {
+            p[(i, j)] = 1.0;
+        }
+        p
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    pub fn sign(&self) -> f64 {
+        self.sign
+    }
+
+    /// Whether some pivot is exactly zero or not finite, so that `U` cannot
+    /// be back-substituted. A tiny pivot only means that the matrix is badly
+    /// conditioned; use [`Mat::rank`] or [`Mat::cond`] to judge that.
+    pub fn is_singular(&self) -> bool {
+        (0..self.size()).any(|i| {
+            let pivot = self.lu[(i, i)];
+            pivot == 0.0 || !pivot.is_finite()
+        })
+    }
+
+    pub fn det(&self) -> f64 {
+        self.sign * (0..self.size()).map(|i| self.lu[(i, i)]).product::<f64>()
+    }
+
+    pub fn solve(&self, b: &Mat) -> Result<Mat, Error> {
+        let n = self.size();
+        if b.rows != n {
+            return Err(Error::ShapeMismatch {
+                left: self.lu.shape(),
+                right: b.shape(),
+            });
+        }
+        if self.is_singular() {
+            return Err(Error::Singular);
+        }
+
+        let mut x = Mat::zeros(n, b.cols);
+        for (i, &p) in self.perm.iter().enumerate() {
+            for k in 0..b.cols {
+                x[(i, k)] = b[(p, k)];
+            }
+        }
+
+        for k in 0..b.cols {
+            for i in 0..n {
+                let s: f64 = (0..i).map(|j| self.lu[(i, j)] * x[(j, k)]).sum();
+                x[(i, k)] -= s;
+            }
+            for i in (0..n).rev() {
+                let s: f64 = (i + 1..n).map(|j| self.lu[(i, j)] * x[(j, k)]).sum();
+                x[(i, k)] = (x[(i, k)] - s) / self.lu[(i, i)];
+            }
+        }
+        Ok(x)
+    }
+
+    pub fn inverse(&self) -> Result<Mat, Error> {
+        self.solve(&Mat::eye(self.size()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_mat_approx_eq;
+
+    #[test]
+    fn test_lu_reconstruct() {
+        let m = Mat::from_vec(3, 3, vec![2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0]);
+        let lu = m.lu();
+        assert_mat_approx_eq!(&lu.p() * &m, &lu.l() * &lu.u(), 1e-12);
+        assert_eq!(lu.permutation(), &[1, 2, 0]);
+    }
+
+    #[test]
+    fn test_lu_det() {
+        let m = Mat::from_vec(3, 3, vec![2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0]);
+        assert!((m.lu().det() + 16.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_lu_solve() {
+        let m = Mat::from_vec(3, 3, vec![2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0]);
+        let b = Mat::from_vec(3, 2, vec![5.0, 1.0, -2.0, 0.0, 9.0, 2.0]);
+        let x = m.lu().solve(&b).unwrap();
+        assert_mat_approx_eq!(&m * &x, b, 1e-12);
+    }
+
+    #[test]
+    fn test_lu_inverse() {
+        let m = Mat::from_vec(3, 3, vec![2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0]);
+        let inv = m.lu().inverse().unwrap();
+        assert_mat_approx_eq!(&m * &inv, Mat::eye(3), 1e-12);
+    }
+
+    #[test]
+    fn test_lu_singular() {
+        let m = Mat::from_vec(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+        let lu = m.lu();
+        assert!(lu.is_singular());
+        assert_eq!(lu.det(), 0.0);
+        assert_eq!(lu.inverse(), Err(Error::Singular));
+    }
+
+    #[test]
+    fn test_lu_badly_scaled() {
+        let m = Mat::from_vec(2, 2, vec![1.0, 0.0, 0.0, 1e-20]);
+        let lu = m.lu();
+        assert!(!lu.is_singular());
+        assert_eq!(lu.det(), 1e-20);
+        let x = lu.solve(&Mat::from_vec(2, 1, vec![2.0, 3e-20])).unwrap();
+        assert_mat_approx_eq!(x, Mat::from_vec(2, 1, vec![2.0, 3.0]), 1e-12);
+        assert_eq!(m.inverse().unwrap()[(1, 1)], 1e20);
+    }
+
+    #[test]
+    fn test_lu_solve_shape_mismatch() {
+        let m = Mat::eye(3);
+        let b = Mat::ones(2, 1);
+        assert_eq!(
+            m.lu().solve(&b),
+            Err(Error::ShapeMismatch {
+                left: (3, 3),
+                right: (2, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_lu_large() {
+        let n = 200;
+        let mut m = Mat::zeros(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                m[(i, j)] = 1.0 / (1.0 + (i as f64 - j as f64).abs());
+            }
+            m[(i, i)] += n as f64;
+        }
+        let inv = m.inverse().unwrap();
+        assert_mat_approx_eq!(&m * &inv, Mat::eye(n), 1e-10);
+    }
+}
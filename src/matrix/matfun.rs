@@ -0,0 +1,414 @@
+use super::Mat;
+use crate::complex::Complex;
+use crate::Error;
+
+const MAX_SQRT_ITERATIONS: usize = 100;
+const MAX_LOG_SQUARE_ROOTS: usize = 64;
+const MAX_LOG_TERMS: usize = 100;
+const DIAGONALIZABLE_COND: f64 = 1e12;
+const EIGENVECTOR_TOL: f64 = 1e-8;
+
+/// Numerator coefficients of the diagonal Padé approximants of `exp` of
+/// degree 3, 5, 7, 9 and 13, paired with the largest 1-norm for which each
+/// one is accurate to double precision (Higham, 2005).
+const PADE: [(f64, &[f64]); 5] = [
+    (1.495585217958292e-2, &[120.0, 60.0, 12.0, 1.0]),
+    (
+        2.53939833006323e-1,
+        &[30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0],
+    ),
+    (
+        9.504178996162932e-1,
+        &[
+            17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0,
+        ],
+    ),
+    (
+        2.097847961257068,
+        &[
+            17643225600.0,
+            8821612800.0,
+            2075673600.0,
+            302702400.0,
+            30270240.0,
+            2162160.0,
+            110880.0,
+            3960.0,
+            90.0,
+            1.0,
+        ],
+    ),
+    (
+        5.371920351148152,
+        &[
+            64764752532480000.0,
+            32382376266240000.0,
+            7771770303897600.0,
+            1187353796428800.0,
+            129060195264000.0,
+            10559470521600.0,
+            670442572800.0,
+            33522128640.0,
+            1323241920.0,
+            40840800.0,
+            960960.0,
+            16380.0,
+            182.0,
+            1.0,
+        ],
+    ),
+];
+
+impl Mat {
+    /// Matrix exponential by Padé approximation with scaling and squaring.
+    /// Fails with `InvalidArgument` if an entry is not finite.
+    pub fn expm(&self) -> Self {
+        self.try_expm().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_expm(&self) -> Result<Self, Error> {
+        self.check_square()?;
+        let norm = self.norm_1();
+        if !norm.is_finite() {
+            return Err(Error::InvalidArgument { name: "matrix" });
+        }
+        if let Some((_, b)) = PADE.iter().find(|(theta, _)| norm <= *theta) {
+            return pade(self, b);
+        }
+
+        let (theta, b) = PADE[PADE.len() - 1];
+        let squarings = (norm / theta).log2().ceil() as i32;
+        let mut r = pade(&(self * 0.5_f64.powi(squarings)), b)?;
+        for _ in 0..squarings {
+            r = r.dot(&r);
+        }
+        Ok(r)
+    }
+
+    /// Principal square root by the Denman-Beavers iteration. Fails with
+    /// `NonRealResult` if the matrix has a negative real eigenvalue and with
+    /// `Singular` if it has a zero eigenvalue.
+    pub fn sqrtm(&self) -> Self {
+        self.try_sqrtm().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_sqrtm(&self) -> Result<Self, Error> {
+        self.check_principal_branch()?;
+        self.denman_beavers()
+    }
+
+    /// Principal logarithm by inverse scaling and squaring: square roots
+    /// are taken until the matrix is close to the identity, where the
+    /// `atanh` series of the logarithm converges quickly.
+    pub fn logm(&self) -> Self {
+        self.try_logm().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_logm(&self) -> Result<Self, Error> {
+        self.check_principal_branch()?;
+        let n = self.rows;
+        let eye = Mat::eye(n);
+
+        let mut a = self.clone();
+        let mut roots = 0;
+        while (&a - &eye).norm_1() > 0.25 {
+            if roots == MAX_LOG_SQUARE_ROOTS {
+                return Err(Error::NoConvergence);
+            }
+            a = a.denman_beavers()?;
+            roots += 1;
+        }
+
+        // log(A) = 2 * atanh(Z) with Z = (A + I)⁻¹ (A - I).
+        let z = (&a + &eye).lu().solve(&(&a - &eye))?;
+        let z2 = z.dot(&z);
+        let mut sum = z.clone();
+        let mut term = z;
+        let mut converged = false;
+        for k in 1..MAX_LOG_TERMS {
+            term = term.dot(&z2);
+            let scaled = &term * (1.0 / (2 * k + 1) as f64);
+            sum += &scaled;
+            if scaled.norm_1() <= f64::EPSILON * sum.norm_1() {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(Error::NoConvergence);
+        }
+        Ok(sum * 2.0_f64.powi(roots as i32 + 1))
+    }
+
+    /// Real power of a square matrix. Integer exponents use repeated
+    /// squaring, of the inverse when negative; other exponents require the
+    /// matrix to be diagonalizable and use the principal branch of `λ^p` on
+    /// its eigenvalues.
+    pub fn powm(&self, p: f64) -> Self {
+        self.try_powm(p).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_powm(&self, p: f64) -> Result<Self, Error> {
+        self.check_square()?;
+        if p.fract() == 0.0 && p.abs() <= u32::MAX as f64 {
+            return if p >= 0.0 {
+                self.try_pow(p as u32)
+            } else {
+                self.try_inverse()?.try_pow(-p as u32)
+            };
+        }
+        self.map_eigenvalues(|z| {
+            if p < 0.0 && z.abs() == 0.0 {
+                return Err(Error::Singular);
+            }
+            Ok(z.pow(p))
+        })
+    }
+
+    fn check_principal_branch(&self) -> Result<(), Error> {
+        let tol = self.rows as f64 * f64::EPSILON * self.norm_max();
+        for z in self.eigenvalues()? {
+            if z.abs() <= tol {
+                return Err(Error::Singular);
+            }
+            if z.im.abs() <= tol && z.re < 0.0 {
+                return Err(Error::NonRealResult);
+            }
+        }
+        Ok(())
+    }
+
+    fn denman_beavers(&self) -> Result<Self, Error> {
+        let n = self.rows;
+        let tol = n as f64 * f64::EPSILON;
+        let mut y = self.clone();
+        let mut z = Mat::eye(n);
+        let mut prev_delta = f64::INFINITY;
+        for _ in 0..MAX_SQRT_ITERATIONS {
+            let y_next = (&y + &z.try_inverse()?) * 0.5;
+            z = (&z + &y.try_inverse()?) * 0.5;
+            let delta = (&y_next - &y).norm_fro();
+            y = y_next;
+
+            // Quadratic convergence stalls at rounding level, which shows
+            // up as a step that no longer shrinks.
+            let norm = y.norm_fro();
+            if delta <= tol * norm || (delta >= prev_delta && delta <= 1e-6 * norm) {
+                return Ok(y);
+            }
+            prev_delta = delta;
+        }
+        Err(Error::NoConvergence)
+    }
+
+    /// Computes `V * f(Λ) * V⁻¹` from an eigendecomposition `A = V Λ V⁻¹`.
+    fn map_eigenvalues(
+        &self,
+        f: impl Fn(Complex) -> Result<Complex, Error>,
+    ) -> Result<Self, Error> {
+        let n = self.rows;
+        let scale = self.norm_max().max(1.0);
+
+        if self.is_symmetric(f64::EPSILON * scale) {
+            let eig = self.symmetric_eigen()?;
+            let values = eig
+                .values
+                .iter()
+                .map(|&x| {
+                    let z = f(Complex::new(x, 0.0))?;
+                    if z.im != 0.0 {
+                        return Err(Error::NonRealResult);
+                    }
+                    Ok(z.re)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let v = &eig.vectors;
+            return Ok(v.hadamard(&Mat::from_vec(1, n, values)).dot(&v.transpose()));
+        }
+
+        let eig = self.eigen()?;
+        // A defective eigenvalue leaves columns of V that are not eigenvectors.
+        let lambda = Mat::from_vec(1, n, eig.values.clone());
+        let residual =
+            &self.map(|x| Complex::new(x, 0.0)).dot(&eig.vectors) - &eig.vectors.hadamard(&lambda);
+        if residual.iter().any(|z| z.abs() > EIGENVECTOR_TOL * scale) {
+            return Err(Error::NotDiagonalizable);
+        }
+        // Invert V through its real embedding [Re V, -Im V; Im V, Re V].
+        let embedding = Mat::from_fn(2 * n, 2 * n, |i, j| {
+            let z = eig.vectors[(i % n, j % n)];
+            match (i < n, j < n) {
+                (true, true) | (false, false) => z.re,
+                (false, true) => z.im,
+                (true, false) => -z.im,
+            }
+        });
+        if embedding.try_cond()? > DIAGONALIZABLE_COND {
+            return Err(Error::NotDiagonalizable);
+        }
+        let inv = embedding
+            .try_inverse()
+            .map_err(|_| Error::NotDiagonalizable)?;
+        let v_inv = Mat::from_fn(n, n, |i, j| Complex::new(inv[(i, j)], inv[(n + i, j)]));
+
+        let values = eig
+            .values
+            .iter()
+            .map(|&z| f(z))
+            .collect::<Result<Vec<_>, _>>()?;
+        let w = eig
+            .vectors
+            .hadamard(&Mat::from_vec(1, n, values))
+            .dot(&v_inv);
+
+        let re = w.map(|z| z.re);
+        let im = w.map(|z| z.im);
+        if im.norm_max() > 1e-8 * re.norm_max().max(1.0) {
+            return Err(Error::NonRealResult);
+        }
+        Ok(re)
+    }
+}
+
+/// Padé approximant `(V - U)⁻¹ (V + U)` of `exp(A)` where `U` collects the
+/// odd and `V` the even terms of the numerator with coefficients `b`.
+fn pade(a: &Mat, b: &[f64]) -> Result<Mat, Error> {
+    let n = a.rows;
+    let a2 = a.dot(a);
+    let mut u = Mat::zeros(n, n);
+    let mut v = Mat::zeros(n, n);
+    let mut power = Mat::eye(n);
+    for (k, pair) in b.chunks(2).enumerate() {
+        if k > 0 {
+            power = power.dot(&a2);
+        }
+        v += &(&power * pair[0]);
+        u += &(&power * pair[1]);
+    }
+    let u = a.dot(&u);
+    (&v - &u).lu().solve(&(&v + &u))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_mat_approx_eq;
+
+    fn sample() -> Mat {
+        // Eigenvalues 1, 2 and 4.
+        Mat::from_vec(3, 3, vec![4.0, 1.0, -1.0, 0.0, 2.0, 1.0, 0.0, 0.0, 1.0])
+    }
+
+    #[test]
+    fn test_expm_known() {
+        assert_mat_approx_eq!(Mat::zeros(3, 3).expm(), Mat::eye(3));
+
+        let nilpotent = Mat::from_vec(2, 2, vec![0.0, 1.0, 0.0, 0.0]);
+        assert_mat_approx_eq!(
+            nilpotent.expm(),
+            Mat::from_vec(2, 2, vec![1.0, 1.0, 0.0, 1.0])
+        );
+
+        let t: f64 = 2.5;
+        let rotation = Mat::from_vec(2, 2, vec![0.0, -t, t, 0.0]);
+        let expected = Mat::from_vec(2, 2, vec![t.cos(), -t.sin(), t.sin(), t.cos()]);
+        assert_mat_approx_eq!(rotation.expm(), expected, 1e-13);
+
+        let d = Mat::from_diag(&[10.0, -3.0, 0.01]);
+        let expected = Mat::from_diag(&[10.0_f64.exp(), (-3.0_f64).exp(), 0.01_f64.exp()]);
+        assert_mat_approx_eq!(d.expm(), expected, 0.0, 1e-13);
+    }
+
+    #[test]
+    fn test_expm_inverse() {
+        let a = sample() * 3.0;
+        assert_mat_approx_eq!(a.expm().dot(&(-&a).expm()), Mat::eye(3), 1e-9);
+        assert!(Mat::ones(2, 3).try_expm().is_err());
+        assert_eq!(
+            Mat::filled(2, 2, f64::NAN).try_expm(),
+            Err(Error::InvalidArgument { name: "matrix" })
+        );
+        assert!(Mat::from_diag(&[1.0, f64::INFINITY]).try_expm().is_err());
+    }
+
+    #[test]
+    fn test_sqrtm() {
+        let a = sample();
+        let s = a.sqrtm();
+        assert_mat_approx_eq!(s.dot(&s), a, 1e-12);
+
+        let spd = Mat::from_vec(2, 2, vec![5.0, 2.0, 2.0, 2.0]);
+        let s = spd.sqrtm();
+        assert_mat_approx_eq!(s.dot(&s), spd, 1e-12);
+        assert_mat_approx_eq!(s, s.transpose(), 1e-12);
+
+        let negative = Mat::from_diag(&[1.0, -4.0]);
+        assert_eq!(negative.try_sqrtm(), Err(Error::NonRealResult));
+        assert_eq!(Mat::zeros(2, 2).try_sqrtm(), Err(Error::Singular));
+
+        // Eigenvalues within rounding of zero or of the negative real axis.
+        let singular = Mat::from_vec(3, 3, vec![2.0, 1.0, 0.5, 1.0, 3.0, 1.0, 3.0, 4.0, 1.5]);
+        assert_eq!(singular.try_sqrtm(), Err(Error::Singular));
+        let nearly_negative = Mat::from_vec(2, 2, vec![-1.0, 1e-17, -1e-15, -1.0]);
+        assert_eq!(nearly_negative.try_sqrtm(), Err(Error::NonRealResult));
+        assert_eq!(nearly_negative.try_logm(), Err(Error::NonRealResult));
+    }
+
+    #[test]
+    fn test_logm() {
+        assert_mat_approx_eq!(Mat::eye(3).logm(), Mat::zeros(3, 3));
+
+        let a = sample();
+        assert_mat_approx_eq!(a.logm().expm(), a, 1e-11);
+
+        let jordan = Mat::from_vec(2, 2, vec![1.0, 1.0, 0.0, 1.0]);
+        assert_mat_approx_eq!(
+            jordan.logm(),
+            Mat::from_vec(2, 2, vec![0.0, 1.0, 0.0, 0.0]),
+            1e-12
+        );
+
+        let big = Mat::from_diag(&[1e3, 0.5]);
+        assert_mat_approx_eq!(
+            big.logm(),
+            Mat::from_diag(&[1e3_f64.ln(), 0.5_f64.ln()]),
+            1e-10
+        );
+    }
+
+    #[test]
+    fn test_powm_integer() {
+        let a = sample();
+        assert_eq!(a.powm(3.0), a.pow(3));
+        let inv = a.inverse().unwrap();
+        assert_mat_approx_eq!(a.powm(-2.0), inv.dot(&inv), 1e-12);
+
+        let singular = Mat::from_vec(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+        assert_eq!(singular.try_powm(-1.0), Err(Error::Singular));
+    }
+
+    #[test]
+    fn test_powm_fractional() {
+        let a = sample();
+        assert_mat_approx_eq!(a.powm(0.5), a.sqrtm(), 1e-10);
+        let cube_root = a.powm(1.0 / 3.0);
+        assert_mat_approx_eq!(cube_root.pow(3), a, 1e-10);
+
+        let spd = Mat::from_vec(2, 2, vec![5.0, 2.0, 2.0, 2.0]);
+        let r = spd.powm(-0.5);
+        assert_mat_approx_eq!(r.dot(&r).dot(&spd), Mat::eye(2), 1e-12);
+
+        // A repeated but non-defective eigenvalue is still diagonalizable.
+        let repeated = Mat::from_vec(3, 3, vec![2.0, 0.0, 1.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0]);
+        let r = repeated.try_powm(0.5).unwrap();
+        assert_mat_approx_eq!(r.dot(&r), repeated, 1e-10);
+        assert_mat_approx_eq!(r, repeated.sqrtm(), 1e-10);
+
+        let jordan = Mat::from_vec(2, 2, vec![1.0, 1.0, 0.0, 1.0]);
+        assert_eq!(jordan.try_powm(0.5), Err(Error::NotDiagonalizable));
+        assert_eq!(
+            Mat::from_diag(&[1.0, -1.0]).try_powm(0.5),
+            Err(Error::NonRealResult)
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use super::{Axis, Mat};
+use crate::scalar::Scalar;
+use crate::Error;
+
+/// Default absolute and relative tolerance of
+/// [`assert_mat_approx_eq!`](crate::assert_mat_approx_eq).
+pub const DEFAULT_APPROX_TOL: f64 = 1e-10;
+
+impl Mat {
+    /// Frobenius norm, the square root of the sum of squared entries. Entries
+    /// are scaled by the largest magnitude first so that the squares cannot
+    /// overflow.
+    pub fn norm_fro(&self) -> f64 {
+        let scale = self.norm_max();
+        if scale == 0.0 || !scale.is_finite() {
+            return scale;
+        }
+        let sum: f64 = self.data.iter().map(|x| (x / scale) * (x / scale)).sum();
+        scale * sum.sqrt()
+    }
+
+    /// Maximum absolute column sum.
+    pub fn norm_1(&self) -> f64 {
+        self.abs().sum(Axis::Cols).norm_max()
+    }
+
+    /// Maximum absolute row sum.
+    pub fn norm_inf(&self) -> f64 {
+        self.abs().sum(Axis::Rows).norm_max()
+    }
+
+    /// Largest absolute entry, NaN if any entry is NaN and 0 for an empty
+    /// matrix.
+    pub fn norm_max(&self) -> f64 {
+        // `f64::max` would skip a NaN entry instead of returning it.
+        self.data
+            .iter()
+            .map(|x| x.abs())
+            .fold(0.0, |acc, x| if acc.is_nan() || acc >= x { acc } else { x })
+    }
+}
+
+impl<T: Scalar> Mat<T> {
+    /// Whether both matrices have the same shape and every pair of entries
+    /// satisfies `|a - b| <= max(abs_tol, rel_tol * max(|a|, |b|))`.
+    pub fn approx_eq(&self, other: &Self, abs_tol: f64, rel_tol: f64) -> bool {
+        self.approx_mismatch(other, abs_tol, rel_tol) == Ok(None)
+    }
+
+    /// First position at which [`Mat::approx_eq`] fails, for the benefit of
+    /// [`assert_mat_approx_eq!`], or a shape mismatch.
+    #[doc(hidden)]
+    pub fn approx_mismatch(
+        &self,
+        other: &Self,
+        abs_tol: f64,
+        rel_tol: f64,
+    ) -> Result<Option<(usize, usize)>, Error> {
+        if self.shape() != other.shape() {
+            return Err(Error::ShapeMismatch {
+                left: self.shape(),
+                right: other.shape(),
+            });
+        }
+        Ok((0..self.rows)
+            .flat_map(|i| (0..self.cols).map(move |j| (i, j)))
+            .find(|&pos| {
+                let (a, b) = (self[pos], other[pos]);
+                let (abs_a, abs_b) = (a.abs(), b.abs());
+                let tol = abs_tol.max(rel_tol * abs_a.max(abs_b));
+                // Subtract the smaller magnitude so that unsigned entries
+                // cannot underflow.
+                let diff = if abs_a >= abs_b { a - b } else { b - a };
+                !(a == b || diff.abs() <= tol)
+            }))
+    }
+}
+
+/// Asserts that two `Mat`s of any element type have the same shape and
+/// approximately equal entries, reporting the first entry that differs.
+///
+/// `assert_mat_approx_eq!(a, b)` uses [`DEFAULT_APPROX_TOL`] for both the
+/// absolute and relative tolerance, `assert_mat_approx_eq!(a, b, tol)` only
+/// an absolute tolerance, and `assert_mat_approx_eq!(a, b, abs_tol, rel_tol)`
+/// both.
+///
+/// [`DEFAULT_APPROX_TOL`]: crate::matrix::DEFAULT_APPROX_TOL
+#[macro_export]
+macro_rules! assert_mat_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_mat_approx_eq!(
+            $left,
+            $right,
+            $crate::matrix::DEFAULT_APPROX_TOL,
+            $crate::matrix::DEFAULT_APPROX_TOL
+        )
+    };
+    ($left:expr, $right:expr, $tol:expr $(,)?) => {
+        $crate::assert_mat_approx_eq!($left, $right, $tol, 0.0)
+    };
+    ($left:expr, $right:expr, $abs_tol:expr, $rel_tol:expr $(,)?) => {{
+        let (left, right): (&$crate::matrix::Mat<_>, &$crate::matrix::Mat<_>) = (&$left, &$right);
+        match left.approx_mismatch(right, $abs_tol, $rel_tol) {
+            Err(e) => panic!("{}", e),
+            Ok(Some(pos)) => panic!(
+                "matrices differ at {:?}: {} != {}\n left: {:?}\nright: {:?}",
+                pos, left[pos], right[pos], left, right
+            ),
+            Ok(None) => {}
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::complex::Complex;
+
+    #[test]
+    fn test_norms() {
+        let m = Mat::from_vec(2, 3, vec![1.0, -2.0, 3.0, -4.0, 5.0, -6.0]);
+        assert_eq!(m.norm_1(), 9.0);
+        assert_eq!(m.norm_inf(), 15.0);
+        assert_eq!(m.norm_max(), 6.0);
+        assert!((m.norm_fro() - 91.0_f64.sqrt()).abs() < 1e-12);
+        assert!(m.norm2() <= m.norm_fro());
+
+        assert_eq!(Mat::zeros(2, 2).norm_fro(), 0.0);
+        let huge = Mat::filled(2, 2, 1e200);
+        assert!((huge.norm_fro() - 2e200).abs() < 1e188);
+
+        for nan in [
+            Mat::from_vec(1, 2, vec![f64::NAN, 1.0]),
+            Mat::from_vec(1, 2, vec![1.0, f64::NAN]),
+        ] {
+            assert!(nan.norm_max().is_nan());
+            assert!(nan.norm_fro().is_nan());
+            assert!(nan.norm_1().is_nan());
+            assert!(nan.norm_inf().is_nan());
+        }
+
+        let empty = Mat::zeros(0, 0);
+        assert_eq!(empty.norm_1(), 0.0);
+        assert_eq!(empty.norm_inf(), 0.0);
+        assert_eq!(empty.norm_max(), 0.0);
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let a = Mat::from_vec(1, 3, vec![1.0, 1000.0, 0.0]);
+        let b = Mat::from_vec(1, 3, vec![1.0 + 1e-9, 1000.001, 1e-12]);
+        assert!(a.approx_eq(&b, 1e-8, 1e-6));
+        assert!(!a.approx_eq(&b, 1e-8, 0.0));
+        assert!(!a.approx_eq(&b, 1e-13, 1e-6));
+        assert!(!a.approx_eq(&Mat::zeros(3, 1), 1.0, 1.0));
+        assert_eq!(a.approx_mismatch(&b, 1e-8, 0.0), Ok(Some((0, 1))));
+        assert_eq!(
+            a.approx_mismatch(&Mat::zeros(3, 1), 1.0, 1.0),
+            Err(Error::ShapeMismatch {
+                left: (1, 3),
+                right: (3, 1)
+            })
+        );
+
+        let inf = Mat::filled(1, 1, f64::INFINITY);
+        assert!(inf.approx_eq(&inf, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_assert_mat_approx_eq() {
+        let a = Mat::eye(2);
+        assert_mat_approx_eq!(a, &a * (1.0 + 1e-12));
+        assert_mat_approx_eq!(a, Mat::eye(2) * 1.001, 1e-2);
+        assert_mat_approx_eq!(a, Mat::eye(2) * 1.001, 0.0, 1e-2);
+
+        let f: Mat<f32> = Mat::from_vec(1, 2, vec![1.0, 2.0]);
+        assert_mat_approx_eq!(f, Mat::from_vec(1, 2, vec![1.0, 2.000001]), 1e-5);
+        let z = Mat::from_vec(1, 2, vec![Complex::new(1.0, 1.0), Complex::new(0.0, -2.0)]);
+        assert_mat_approx_eq!(z, &z * Complex::new(1.0, 1e-12));
+        let u: Mat<u8> = Mat::from_vec(1, 2, vec![3, 5]);
+        assert!(u.approx_eq(&Mat::from_vec(1, 2, vec![4, 5]), 1.0, 0.0));
+        assert!(!u.approx_eq(&Mat::from_vec(1, 2, vec![5, 5]), 1.0, 0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "matrices differ at (0, 1): 0 - 2i != 0 - 3i")]
+    fn test_assert_mat_approx_eq_complex_fails() {
+        let z = Mat::from_vec(1, 2, vec![Complex::new(1.0, 1.0), Complex::new(0.0, -2.0)]);
+        let w = Mat::from_vec(1, 2, vec![Complex::new(1.0, 1.0), Complex::new(0.0, -3.0)]);
+        assert_mat_approx_eq!(z, w, 1e-3);
+    }
+
+    #[test]
+    #[should_panic(expected = "matrices differ at (0, 0): 1 != 1.1")]
+    fn test_assert_mat_approx_eq_fails() {
+        assert_mat_approx_eq!(Mat::eye(2), Mat::eye(2) * 1.1, 1e-3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix shapes 2x2 and 2x3 are not compatible.")]
+    fn test_assert_mat_approx_eq_shape_mismatch() {
+        assert_mat_approx_eq!(Mat::<f64>::eye(2), Mat::zeros(2, 3));
+    }
+}
@@ -0,0 +1,452 @@
+use super::Mat;
+use crate::Error;
+
+/// Householder QR factorization, `A = Q * R`.
+///
+/// `R` lives on and above the diagonal of `qr`; below it are the tails of the
+/// Householder vectors `v_k` (with `v_k[0] = 1` implied), so that
+/// `H_k = I - tau[k] * v_k * v_kᵀ` and `Q = H_0 * H_1 * ... * H_{k-1}`.
+/// Internally `perm` records column swaps, which only [`PivotedQr`] makes.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Qr {
+    qr: Mat,
+    tau: Vec<f64>,
+    perm: Vec<usize>,
+}
+
+/// Householder QR factorization with column pivoting, `A * P = Q * R`.
+///
+/// Each step moves the remaining column of largest norm to the front, so the
+/// diagonal of `R` decreases in magnitude and its negligible tail gives the
+/// numerical rank.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PivotedQr {
+    qr: Qr,
+}
+
+/// Result of [`Mat::lstsq`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct Lstsq {
+    pub solution: Mat,
+    pub residuals: Vec<f64>,
+    pub rank: usize,
+}
+
+impl Mat {
+    pub fn qr(&self) -> Qr {
+        Qr::factor(self, false)
+    }
+
+    pub fn qr_pivoted(&self) -> PivotedQr {
+        PivotedQr {
+            qr: Qr::factor(self, true),
+        }
+    }
+
+    /// Least-squares solution of `A * x = b` by QR with column pivoting.
+    /// For rank-deficient `A` the solution is the one of minimum norm, the
+    /// same as `pinv().dot(b)`.
+    pub fn lstsq(&self, b: &Self) -> Result<Lstsq, Error> {
+        if b.rows != self.rows {
+            return Err(Error::ShapeMismatch {
+                left: self.shape(),
+                right: b.shape(),
+            });
+        }
+
+        let pivoted = self.qr_pivoted();
+        let rank = pivoted.rank();
+        let qr = &pivoted.qr;
+        let mut qtb = b.clone();
+        qr.apply_qt(&mut qtb);
+
+        // Complete orthogonal decomposition: with `[R11 R12]ᵀ = Z * U` for
+        // the leading `rank` rows of `R`, the minimum-norm solution in
+        // pivoted order is `Z[.., ..rank] * U⁻ᵀ * (Qᵀ b)[..rank]`.
+        let top = Self::from_fn(
+            self.cols,
+            rank,
+            |i, j| if i >= j { qr.qr[(j, i)] } else { 0.0 },
+        );
+        let z = Qr::factor(&top, false);
+        let mut y = Self::filled(self.cols, b.cols, 0.0);
+        for c in 0..b.cols {
+            for i in 0..rank {
+                let s: f64 = (0..i).map(|j| z.qr[(j, i)] * y[(j, c)]).sum();
+                y[(i, c)] = (qtb[(i, c)] - s) / z.qr[(i, i)];
+            }
+        }
+        z.apply_q(&mut y);
+
+        let mut solution = Self::filled(self.cols, b.cols, 0.0);
+        for (i, &p) in qr.perm.iter().enumerate() {
+            for c in 0..b.cols {
+                solution[(p, c)] = y[(i, c)];
+            }
+        }
+
+        let residuals = (0..b.cols)
+            .map(|c| (rank..self.rows).map(|i| qtb[(i, c)] * qtb[(i, c)]).sum())
+            .collect();
+
+        Ok(Lstsq {
+            solution,
+            residuals,
+            rank,
+        })
+    }
+}
+
+impl Qr {
+    fn factor(a: &Mat, pivoting: bool) -> Self {
+        let (m, n) = a.shape();
+        let mut qr = a.clone();
+        let mut tau = vec![0.0; m.min(n)];
+        let mut perm: Vec<usize> = (0..n).collect();
+
+        for (k, tau_k) in tau.iter_mut().enumerate() {
+            if pivoting {
+                let col_norm = |qr: &Mat, j: usize| (k..m).map(|i| qr[(i, j)] * qr[(i, j)]).sum();
+                let p = (k..n)
+                    .max_by(|&a, &b| f64::total_cmp(&col_norm(&qr, a), &col_norm(&qr, b)))
+                    .unwrap();
+                if p != k {
+                    for i in 0..m {
+                        qr.data.swap(i * n + k, i * n + p);
+                    }
+                    perm.swap(k, p);
+                }
+            }
+
+            let tail_norm = (k + 1..m).map(|i| qr[(i, k)] * qr[(i, k)]).sum::<f64>();
+            if tail_norm == 0.0 {
+                continue;
+            }
+
+            let x0 = qr[(k, k)];
+            let norm = (x0 * x0 + tail_norm).sqrt();
+            let alpha = if x0 > 0.0 { -norm } else { norm };
+            let v0 = x0 - alpha;
+            for i in k + 1..m {
+                qr[(i, k)] /= v0;
+            }
+            *tau_k = (alpha - x0) / alpha;
+            qr[(k, k)] = alpha;
+
+            for j in k + 1..n {
+                let s = qr[(k, j)] + (k + 1..m).map(|i| qr[(i, k)] * qr[(i, j)]).sum::<f64>();
+                let s = *tau_k * s;
+                qr[(k, j)] -= s;
+                for i in k + 1..m {
+                    qr[(i, j)] -= s * qr[(i, k)];
+                }
+            }
+        }
+
+        Qr { qr, tau, perm }
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        self.qr.shape()
+    }
+
+    pub fn q(&self) -> Mat {
+        let mut q = Mat::eye(self.qr.rows);
+        self.apply_q(&mut q);
+        q
+    }
+
+    pub fn r(&self) -> Mat {
+        let (m, n) = self.shape();
+        let mut r = Mat::zeros(m, n);
+        for i in 0..m.min(n) {
+            for j in i..n {
+                r[(i, j)] = self.qr[(i, j)];
+            }
+        }
+        r
+    }
+
+    pub fn thin_q(&self) -> Mat {
+        let (m, n) = self.shape();
+        let k = m.min(n);
+        let mut q = Mat::zeros(m, k);
+        for i in 0..k {
+            q[(i, i)] = 1.0;
+        }
+        self.apply_q(&mut q);
+        q
+    }
+
+    pub fn thin_r(&self) -> Mat {
+        let (m, n) = self.shape();
+        let k = m.min(n);
+        let mut r = Mat::zeros(k, n);
+        for i in 0..k {
+            for j in i..n {
+                r[(i, j)] = self.qr[(i, j)];
+            }
+        }
+        r
+    }
+
+    /// Number of diagonal entries of `R` above `max(m, n) * ε * max |r_ii|`.
+    fn count_nonzero_diag(&self) -> usize {
+        let (m, n) = self.shape();
+        let k = m.min(n);
+        let max_diag = (0..k).map(|i| self.qr[(i, i)].abs()).fold(0.0, f64::max);
+        let tol = m.max(n) as f64 * f64::EPSILON * max_diag;
+        (0..k).filter(|&i| self.qr[(i, i)].abs() > tol).count()
+    }
+
+    /// Whether some diagonal entry of `R` is negligible. With at least as
+    /// many rows as columns, that is when `A` lacks full column rank.
+    pub(crate) fn is_singular(&self) -> bool {
+        let (m, n) = self.shape();
+        self.count_nonzero_diag() < m.min(n)
+    }
+
+    /// Least-squares solution of `A * x = b` for a full-rank `A` with at
+    /// least as many rows as columns. A wide `A` gives
+    /// [`Error::Underdetermined`]; [`Mat::solve`] and [`Mat::lstsq`] handle
+    /// that case.
+    pub fn solve(&self, b: &Mat) -> Result<Mat, Error> {
+        let (m, n) = self.shape();
+        if m < n {
+            return Err(Error::Underdetermined { rows: m, cols: n });
+        }
+        if b.rows != m {
+            return Err(Error::ShapeMismatch {
+                left: self.shape(),
+                right: b.shape(),
+            });
+        }
+        if self.is_singular() {
+            return Err(Error::Singular);
+        }
+
+        let mut qtb = b.clone();
+        self.apply_qt(&mut qtb);
+        let x = self.solve_r(&qtb);
+
+        let mut result = Mat::zeros(n, b.cols);
+        for (i, &p) in self.perm.iter().enumerate() {
+            for c in 0..b.cols {
+                result[(p, c)] = x[(i, c)];
+            }
+        }
+        Ok(result)
+    }
+
+    fn reflect(&self, k: usize, b: &mut Mat) {
+        let m = self.qr.rows;
+        for j in 0..b.cols {
+            let s = b[(k, j)] + (k + 1..m).map(|i| self.qr[(i, k)] * b[(i, j)]).sum::<f64>();
+            let s = self.tau[k] * s;
+            b[(k, j)] -= s;
+            for i in k + 1..m {
+                b[(i, j)] -= s * self.qr[(i, k)];
+            }
+        }
+    }
+
+    /// Overwrites `b` with `Qᵀ * b`.
+    pub(crate) fn apply_qt(&self, b: &mut Mat) {
+        for k in 0..self.tau.len() {
+            self.reflect(k, b);
+        }
+    }
+
+    /// Overwrites `b` with `Q * b`.
+    pub(crate) fn apply_q(&self, b: &mut Mat) {
+        for k in (0..self.tau.len()).rev() {
+            self.reflect(k, b);
+        }
+    }
+
+    /// Solves `R[..k, ..k] * x = b[..k]` by back substitution.
+    pub(crate) fn solve_r(&self, b: &Mat) -> Mat {
+        let k = self.tau.len();
+        let mut x = Mat::zeros(k, b.cols);
+        for c in 0..b.cols {
+            for i in (0..k).rev() {
+                let s: f64 = (i + 1..k).map(|j| self.qr[(i, j)] * x[(j, c)]).sum();
+                x[(i, c)] = (b[(i, c)] - s) / self.qr[(i, i)];
+            }
+        }
+        x
+    }
+
+    /// Solves `R[..k, ..k]ᵀ * y = b` by forward substitution.
+    pub(crate) fn solve_rt(&self, b: &Mat) -> Mat {
+        let k = self.tau.len();
+        let mut y = Mat::zeros(k, b.cols);
+        for c in 0..b.cols {
+            for i in 0..k {
+                let s: f64 = (0..i).map(|j| self.qr[(j, i)] * y[(j, c)]).sum();
+                y[(i, c)] = (b[(i, c)] - s) / self.qr[(i, i)];
+            }
+        }
+        y
+    }
+}
+
+impl PivotedQr {
+    pub fn shape(&self) -> (usize, usize) {
+        self.qr.shape()
+    }
+
+    pub fn q(&self) -> Mat {
+        self.qr.q()
+    }
+
+    pub fn r(&self) -> Mat {
+        self.qr.r()
+    }
+
+    /// Column `j` of `A * P` is column `perm()[j]` of `A`.
+    pub fn perm(&self) -> &[usize] {
+        &self.qr.perm
+    }
+
+    pub fn p(&self) -> Mat {
+        let n = self.qr.perm.len();
+        let mut p = Mat::zeros(n, n);
+        for (j, &i) in self.qr.perm.iter().enumerate() {
+            p[(i, j)] = 1.0;
+        }
+        p
+    }
+
+    /// Number of diagonal entries of `R` above `max(m, n) * ε * max_i |r_ii|`.
+    pub fn rank(&self) -> usize {
+        self.qr.count_nonzero_diag()
+    }
+
+    pub fn is_rank_deficient(&self) -> bool {
+        let (m, n) = self.shape();
+        self.rank() < m.min(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_mat_approx_eq;
+
+    fn sample() -> Mat {
+        Mat::from_vec(
+            4,
+            3,
+            vec![
+                1.0, -1.0, 4.0, 1.0, 4.0, -2.0, 1.0, 4.0, 2.0, 1.0, -1.0, 0.0,
+            ],
+        )
+    }
+
+    #[test]
+    fn test_qr_full() {
+        let m = sample();
+        let qr = m.qr();
+        let (q, r) = (qr.q(), qr.r());
+        assert_eq!(q.shape(), (4, 4));
+        assert_eq!(r.shape(), (4, 3));
+        assert_mat_approx_eq!(q.transpose().dot(&q), Mat::eye(4), 1e-12);
+        assert_mat_approx_eq!(q.dot(&r), m, 1e-12);
+        for i in 1..4 {
+            for j in 0..i.min(3) {
+                assert_eq!(r[(i, j)], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_qr_thin() {
+        let m = sample();
+        let qr = m.qr();
+        let (q, r) = (qr.thin_q(), qr.thin_r());
+        assert_eq!(q.shape(), (4, 3));
+        assert_eq!(r.shape(), (3, 3));
+        assert_mat_approx_eq!(q.transpose().dot(&q), Mat::eye(3), 1e-12);
+        assert_mat_approx_eq!(q.dot(&r), m, 1e-12);
+    }
+
+    #[test]
+    fn test_qr_wide() {
+        let m = sample().transpose();
+        let qr = m.qr();
+        assert_mat_approx_eq!(qr.q().dot(&qr.r()), m, 1e-12);
+        assert_mat_approx_eq!(qr.thin_q().dot(&qr.thin_r()), m, 1e-12);
+    }
+
+    #[test]
+    fn test_qr_solve() {
+        let m = sample();
+        let x = Mat::from_vec(3, 1, vec![1.0, 2.0, -1.0]);
+        let b = m.dot(&x);
+        assert_mat_approx_eq!(m.qr().solve(&b).unwrap(), x, 1e-12);
+    }
+
+    #[test]
+    fn test_qr_pivoted_rank() {
+        let m = Mat::from_vec(3, 2, vec![0.0, 1.0, 0.0, 0.0, 0.0, 0.0]);
+        let qr = m.qr_pivoted();
+        assert_eq!(qr.perm(), &[1, 0]);
+        assert_mat_approx_eq!(qr.q().dot(&qr.r()), m.dot(&qr.p()), 1e-12);
+        assert_eq!(qr.rank(), 1);
+        assert!(qr.is_rank_deficient());
+        assert_eq!(sample().qr_pivoted().rank(), 3);
+    }
+
+    #[test]
+    fn test_qr_solve_errors() {
+        let wide = sample().transpose();
+        let b = Mat::zeros(3, 1);
+        assert_eq!(
+            wide.qr().solve(&b),
+            Err(Error::Underdetermined { rows: 3, cols: 4 })
+        );
+        assert_eq!(
+            sample().qr().solve(&b),
+            Err(Error::ShapeMismatch {
+                left: (4, 3),
+                right: (3, 1)
+            })
+        );
+        let singular = Mat::from_vec(3, 2, vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+        assert_eq!(singular.qr().solve(&b), Err(Error::Singular));
+    }
+
+    #[test]
+    fn test_lstsq() {
+        // Least-squares line through (0, 1), (1, 3), (2, 4), (3, 4).
+        let a = Mat::from_vec(4, 2, vec![1.0, 0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 3.0]);
+        let b = Mat::from_vec(4, 1, vec![1.0, 3.0, 4.0, 4.0]);
+        let fit = a.lstsq(&b).unwrap();
+        assert_eq!(fit.rank, 2);
+        assert_mat_approx_eq!(fit.solution, Mat::from_vec(2, 1, vec![1.5, 1.0]), 1e-12);
+        assert!((fit.residuals[0] - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_lstsq_rank_deficient() {
+        let a = Mat::from_vec(3, 2, vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+        let b = Mat::from_vec(3, 1, vec![5.0, 10.0, 15.0]);
+        let fit = a.lstsq(&b).unwrap();
+        assert_eq!(fit.rank, 1);
+        assert_mat_approx_eq!(a.dot(&fit.solution), b, 1e-12);
+        assert!(fit.residuals[0].abs() < 1e-12);
+        // Minimum norm (1, 2) rather than the basic solution (0, 2.5).
+        assert_mat_approx_eq!(fit.solution, Mat::from_vec(2, 1, vec![1.0, 2.0]), 1e-12);
+
+        let wide = Mat::from_vec(
+            3,
+            4,
+            vec![1.0, 2.0, 0.0, 1.0, 2.0, 4.0, 1.0, 3.0, 3.0, 6.0, 1.0, 4.0],
+        );
+        let b = Mat::from_vec(3, 2, vec![1.0, 0.0, 2.0, 1.0, 4.0, -1.0]);
+        let fit = wide.lstsq(&b).unwrap();
+        assert_eq!(fit.rank, 2);
+        assert_mat_approx_eq!(fit.solution, wide.pinv().dot(&b), 1e-12);
+    }
+}
@@ -0,0 +1,254 @@
+use std::f64::consts::TAU;
+
+use rand::Rng;
+
+use super::Mat;
+use crate::Error;
+
+/// Standard normal sample by the Box-Muller transform.
+pub(crate) fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    // `gen` is uniform on [0, 1); flip it to (0, 1] so the logarithm is finite.
+    let u1 = 1.0 - rng.gen::<f64>();
+    let u2 = rng.gen::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (TAU * u2).cos()
+}
+
+impl Mat {
+    /// Entries drawn uniformly from `[lo, hi)`.
+    pub fn random_uniform<R: Rng + ?Sized>(
+        rows: usize,
+        cols: usize,
+        lo: f64,
+        hi: f64,
+        rng: &mut R,
+    ) -> Self {
+        Self::try_random_uniform(rows, cols, lo, hi, rng).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Fails unless `lo < hi`, both bounds are finite and so is `hi - lo`.
+    pub fn try_random_uniform<R: Rng + ?Sized>(
+        rows: usize,
+        cols: usize,
+        lo: f64,
+        hi: f64,
+        rng: &mut R,
+    ) -> Result<Self, Error> {
+        if !lo.is_finite() {
+            return Err(Error::InvalidArgument { name: "lo" });
+        }
+        if lo >= hi || !(hi - lo).is_finite() {
+            return Err(Error::InvalidArgument { name: "hi" });
+        }
+        // `gen_range` rejects samples that round up to `hi`, which
+        // `lo + (hi - lo) * u` does not.
+        Ok(Self::from_fn(rows, cols, |_, _| rng.gen_range(lo..hi)))
+    }
+
+    /// Entries drawn from a normal distribution with the given mean and
+    /// standard deviation.
+    pub fn random_normal<R: Rng + ?Sized>(
+        rows: usize,
+        cols: usize,
+        mean: f64,
+        std_dev: f64,
+        rng: &mut R,
+    ) -> Self {
+        Self::try_random_normal(rows, cols, mean, std_dev, rng).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Fails unless `mean` is finite and `std_dev` is finite and
+    /// non-negative.
+    pub fn try_random_normal<R: Rng + ?Sized>(
+        rows: usize,
+        cols: usize,
+        mean: f64,
+        std_dev: f64,
+        rng: &mut R,
+    ) -> Result<Self, Error> {
+        if !mean.is_finite() {
+            return Err(Error::InvalidArgument { name: "mean" });
+        }
+        if !(0.0..f64::INFINITY).contains(&std_dev) {
+            return Err(Error::InvalidArgument { name: "std_dev" });
+        }
+        let data = (0..rows * cols)
+            .map(|_| mean + std_dev * standard_normal(rng))
+            .collect();
+        Ok(Self::from_vec(rows, cols, data))
+    }
+
+    /// Orthogonal matrix distributed uniformly (Haar measure) over `O(n)`.
+    ///
+    /// Takes the `Q` factor of a Gaussian matrix and flips its columns so
+    /// that `R` has a positive diagonal; without the sign fix the result
+    /// would be biased by the Householder sign convention.
+    pub fn random_orthogonal<R: Rng + ?Sized>(n: usize, rng: &mut R) -> Self {
+        let qr = Self::random_normal(n, n, 0.0, 1.0, rng).qr();
+        let (mut q, r) = (qr.q(), qr.r());
+        for j in 0..n {
+            if r[(j, j)] < 0.0 {
+                let mut col = q.col_mut(j);
+                col *= -1.0;
+            }
+        }
+        q
+    }
+
+    /// Symmetric positive definite matrix `Q * D * Qᵀ` with a Haar-random
+    /// `Q` and eigenvalues drawn uniformly from `[1, n + 1)`, so the
+    /// condition number stays bounded by `n + 1`. `n == 0` gives the empty
+    /// `0x0` matrix, as does [`Mat::random_orthogonal`].
+    pub fn random_spd<R: Rng + ?Sized>(n: usize, rng: &mut R) -> Self {
+        let q = Self::random_orthogonal(n, rng);
+        let eigenvalues = Self::from_fn(1, n, |_, _| rng.gen_range(1.0..n as f64 + 1.0));
+        let a = q.hadamard(&eigenvalues).dot(&q.transpose());
+        // Symmetrize away the rounding noise of the product.
+        (&a + &a.transpose()) * 0.5
+    }
+
+    /// Matrix whose entries are non-zero with probability `density`, the
+    /// non-zero ones drawn from a standard normal distribution.
+    pub fn random_sparse<R: Rng + ?Sized>(
+        rows: usize,
+        cols: usize,
+        density: f64,
+        rng: &mut R,
+    ) -> Self {
+        Self::try_random_sparse(rows, cols, density, rng).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Fails unless `density` lies in `[0, 1]`, which also rules out NaN.
+    pub fn try_random_sparse<R: Rng + ?Sized>(
+        rows: usize,
+        cols: usize,
+        density: f64,
+        rng: &mut R,
+    ) -> Result<Self, Error> {
+        if !(0.0..=1.0).contains(&density) {
+            return Err(Error::InvalidArgument { name: "density" });
+        }
+        Ok(Self::from_fn(rows, cols, |_, _| {
+            if rng.gen_bool(density) {
+                standard_normal(rng)
+            } else {
+                0.0
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_mat_approx_eq;
+    use crate::matrix::Axis;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_seeded_reproducible() {
+        let a = Mat::random_normal(3, 3, 0.0, 1.0, &mut StdRng::seed_from_u64(7));
+        let b = Mat::random_normal(3, 3, 0.0, 1.0, &mut StdRng::seed_from_u64(7));
+        let c = Mat::random_normal(3, 3, 0.0, 1.0, &mut StdRng::seed_from_u64(8));
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_random_uniform() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let m = Mat::random_uniform(50, 40, -2.0, 3.0, &mut rng);
+        assert!(m.min(Axis::All)[(0, 0)] >= -2.0);
+        assert!(m.max(Axis::All)[(0, 0)] < 3.0);
+        assert!((m.mean(Axis::All)[(0, 0)] - 0.5).abs() < 0.1);
+
+        // Spacing of 1 ulp, where scaling a unit sample would round up to `hi`.
+        let (lo, hi) = (1.0, 1.0 + f64::EPSILON);
+        let m = Mat::random_uniform(10, 10, lo, hi, &mut rng);
+        assert!(m.iter().all(|&x| x == lo));
+
+        for (lo, hi, name) in [
+            (1.0, 1.0, "hi"),
+            (3.0, -2.0, "hi"),
+            (0.0, f64::NAN, "hi"),
+            (0.0, f64::INFINITY, "hi"),
+            (-f64::MAX, f64::MAX, "hi"),
+            (f64::NAN, 1.0, "lo"),
+            (f64::NEG_INFINITY, 1.0, "lo"),
+        ] {
+            assert_eq!(
+                Mat::try_random_uniform(2, 2, lo, hi, &mut rng),
+                Err(Error::InvalidArgument { name })
+            );
+        }
+    }
+
+    #[test]
+    fn test_random_normal() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let m = Mat::random_normal(100, 100, 1.0, 2.0, &mut rng);
+        assert!((m.mean(Axis::All)[(0, 0)] - 1.0).abs() < 0.05);
+        assert!((m.std(Axis::All)[(0, 0)] - 2.0).abs() < 0.05);
+
+        assert_eq!(
+            Mat::random_normal(2, 2, 3.0, 0.0, &mut rng),
+            Mat::filled(2, 2, 3.0)
+        );
+        for std_dev in [-1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                Mat::try_random_normal(2, 2, 0.0, std_dev, &mut rng),
+                Err(Error::InvalidArgument { name: "std_dev" })
+            );
+        }
+        assert_eq!(
+            Mat::try_random_normal(2, 2, f64::NAN, 1.0, &mut rng),
+            Err(Error::InvalidArgument { name: "mean" })
+        );
+    }
+
+    #[test]
+    fn test_random_orthogonal() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let q = Mat::random_orthogonal(5, &mut rng);
+        assert_mat_approx_eq!(q.transpose().dot(&q), Mat::eye(5), 1e-12);
+        assert!((q.det().abs() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_random_empty() {
+        let mut rng = StdRng::seed_from_u64(9);
+        assert_eq!(Mat::random_orthogonal(0, &mut rng).shape(), (0, 0));
+        assert_eq!(Mat::random_spd(0, &mut rng).shape(), (0, 0));
+    }
+
+    #[test]
+    fn test_random_spd() {
+        let mut rng = StdRng::seed_from_u64(4);
+        let a = Mat::random_spd(6, &mut rng);
+        assert_eq!(a, a.transpose());
+        assert!(a.cholesky().is_ok());
+        let eig = a.symmetric_eigen().unwrap();
+        assert!(eig.values[0] >= 1.0 - 1e-10 && eig.values[5] < 7.0 + 1e-10);
+    }
+
+    #[test]
+    fn test_random_sparse() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let m = Mat::random_sparse(100, 100, 0.1, &mut rng);
+        let nonzero = m.data.iter().filter(|&&x| x != 0.0).count();
+        assert!((800..1200).contains(&nonzero));
+        assert_eq!(Mat::random_sparse(3, 3, 0.0, &mut rng), Mat::zeros(3, 3));
+        for density in [f64::NAN, -0.1, 1.5] {
+            assert_eq!(
+                Mat::try_random_sparse(3, 3, density, &mut rng),
+                Err(Error::InvalidArgument { name: "density" })
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid value for `density`.")]
+    fn test_random_sparse_nan_density_panics() {
+        let _ = Mat::random_sparse(2, 2, f64::NAN, &mut StdRng::seed_from_u64(6));
+    }
+}
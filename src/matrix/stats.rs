@@ -0,0 +1,336 @@
+use super::Mat;
+use crate::scalar::Scalar;
+use crate::Error;
+
+/// Direction of a reduction.
+///
+/// `Rows` reduces each row to a single value and yields an `m x 1` column,
+/// `Cols` reduces each column and yields a `1 x n` row, and `All` reduces the
+/// whole matrix to `1 x 1`. Keeping the reduced axis means the result
+/// broadcasts back against the original, e.g. `&m - &m.mean(Axis::Cols)`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Axis {
+    Rows,
+    Cols,
+    All,
+}
+
+impl<T: Scalar> Mat<T> {
+    pub fn sum(&self, axis: Axis) -> Self {
+        self.fold_axis(axis, T::zero(), |acc, val| acc + val)
+    }
+
+    pub fn prod(&self, axis: Axis) -> Self {
+        self.fold_axis(axis, T::one(), |acc, val| acc * val)
+    }
+
+    /// Running sum along the axis, in row-major order for `Axis::All`.
+    pub fn cumsum(&self, axis: Axis) -> Self {
+        let mut out = self.clone();
+        let cols = self.cols;
+        match axis {
+            Axis::Rows => {
+                for i in 0..self.rows {
+                    for j in 1..cols {
+                        out.data[i * cols + j] =
+                            out.data[i * cols + j - 1] + out.data[i * cols + j];
+                    }
+                }
+            }
+            Axis::Cols => {
+                for i in 1..self.rows {
+                    for j in 0..cols {
+                        out.data[i * cols + j] =
+                            out.data[(i - 1) * cols + j] + out.data[i * cols + j];
+                    }
+                }
+            }
+            Axis::All => {
+                for k in 1..out.data.len() {
+                    out.data[k] = out.data[k - 1] + out.data[k];
+                }
+            }
+        }
+        out
+    }
+
+    fn fold_axis<U: Scalar>(&self, axis: Axis, init: U, f: impl Fn(U, T) -> U) -> Mat<U> {
+        let (rows, cols) = self.reduced_shape(axis);
+        let mut out = Mat::filled(rows, cols, init);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                let k = lane(axis, i, j);
+                out.data[k] = f(out.data[k], self.data[i * self.cols + j]);
+            }
+        }
+        out
+    }
+
+    fn reduced_shape(&self, axis: Axis) -> (usize, usize) {
+        match axis {
+            Axis::Rows => (self.rows, 1),
+            Axis::Cols => (1, self.cols),
+            Axis::All => (1, 1),
+        }
+    }
+
+    /// Number of elements folded into each entry of a reduction.
+    fn lane_len(&self, axis: Axis) -> usize {
+        match axis {
+            Axis::Rows => self.cols,
+            Axis::Cols => self.rows,
+            Axis::All => self.rows * self.cols,
+        }
+    }
+}
+
+fn lane(axis: Axis, row: usize, col: usize) -> usize {
+    match axis {
+        Axis::Rows => row,
+        Axis::Cols => col,
+        Axis::All => 0,
+    }
+}
+
+impl Mat {
+    pub fn mean(&self, axis: Axis) -> Self {
+        let n = self.lane_len(axis) as f64;
+        self.sum(axis).map(|val| val / n)
+    }
+
+    /// Sample variance, normalized by `n - 1` so that it matches the diagonal
+    /// of [`Mat::covariance`]. A lane of length 1 has no sample variance and
+    /// gives `0 / 0 = NaN`, as does `std`.
+    pub fn var(&self, axis: Axis) -> Self {
+        let n = self.lane_len(axis) as f64;
+        (self - &self.mean(axis))
+            .map(|val| val * val)
+            .sum(axis)
+            .map(|val| val / (n - 1.0))
+    }
+
+    pub fn std(&self, axis: Axis) -> Self {
+        self.var(axis).map(f64::sqrt)
+    }
+
+    /// Smallest entry of every lane. A NaN entry makes its lane NaN, as it
+    /// does for `sum` and `mean`. Panics if the lanes are empty.
+    pub fn min(&self, axis: Axis) -> Self {
+        self.try_min(axis).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Fails with `Empty` when the lanes are empty, e.g. for `Axis::Rows` of
+    /// an `m x 0` matrix, as they have no smallest entry.
+    pub fn try_min(&self, axis: Axis) -> Result<Self, Error> {
+        self.pick(axis, |a, b| a < b)
+    }
+
+    /// Largest entry of every lane, with the same NaN and empty lane rules
+    /// as [`Mat::min`].
+    pub fn max(&self, axis: Axis) -> Self {
+        self.try_max(axis).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_max(&self, axis: Axis) -> Result<Self, Error> {
+        self.pick(axis, |a, b| a > b)
+    }
+
+    /// Position `(row, col)` of the smallest entry of every lane, or of its
+    /// first NaN. Panics if the lanes are empty.
+    pub fn argmin(&self, axis: Axis) -> Vec<(usize, usize)> {
+        self.try_argmin(axis).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_argmin(&self, axis: Axis) -> Result<Vec<(usize, usize)>, Error> {
+        self.arg_by(axis, |a, b| a < b)
+    }
+
+    /// Position `(row, col)` of the largest entry of every lane, or of its
+    /// first NaN. Panics if the lanes are empty.
+    pub fn argmax(&self, axis: Axis) -> Vec<(usize, usize)> {
+        self.try_argmax(axis).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_argmax(&self, axis: Axis) -> Result<Vec<(usize, usize)>, Error> {
+        self.arg_by(axis, |a, b| a > b)
+    }
+
+    /// Sample covariance matrix treating each column as a variable and each
+    /// row as an observation. A single observation gives NaN entries.
+    pub fn covariance(&self) -> Self {
+        let centered = self - &self.mean(Axis::Cols);
+        let n = self.rows as f64;
+        centered
+            .transpose()
+            .dot(&centered)
+            .map(|val| val / (n - 1.0))
+    }
+
+    /// Pearson correlation coefficients between the columns.
+    pub fn correlation(&self) -> Self {
+        let cov = self.covariance();
+        let std: Vec<f64> = (0..cov.rows).map(|i| cov[(i, i)].sqrt()).collect();
+        let mut corr = cov;
+        for i in 0..corr.rows {
+            for j in 0..corr.cols {
+                corr[(i, j)] /= std[i] * std[j];
+            }
+        }
+        corr
+    }
+
+    fn pick(&self, axis: Axis, better: impl Fn(f64, f64) -> bool) -> Result<Self, Error> {
+        let (rows, cols) = self.reduced_shape(axis);
+        let data = self
+            .arg_by(axis, better)?
+            .into_iter()
+            .map(|pos| self[pos])
+            .collect();
+        Ok(Mat::from_vec(rows, cols, data))
+    }
+
+    /// Position of the entry of every lane that is `better` than all others,
+    /// where a NaN beats everything so that the result does not depend on
+    /// where in the lane it sits.
+    fn arg_by(
+        &self,
+        axis: Axis,
+        better: impl Fn(f64, f64) -> bool,
+    ) -> Result<Vec<(usize, usize)>, Error> {
+        let (rows, cols) = self.reduced_shape(axis);
+        let mut best: Vec<Option<(usize, usize)>> = vec![None; rows * cols];
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                let k = lane(axis, i, j);
+                let val = self[(i, j)];
+                match best[k] {
+                    Some(pos)
+                        if self[pos].is_nan() || !(val.is_nan() || better(val, self[pos])) => {}
+                    _ => best[k] = Some((i, j)),
+                }
+            }
+        }
+        best.into_iter()
+            .map(|pos| pos.ok_or(Error::Empty))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Mat {
+        Mat::from_vec(3, 2, vec![1.0, 6.0, 2.0, 4.0, 6.0, 2.0])
+    }
+
+    #[test]
+    fn test_sum_prod() {
+        let m = sample();
+        assert_eq!(m.sum(Axis::Rows).data, vec![7.0, 6.0, 8.0]);
+        assert_eq!(m.sum(Axis::Rows).shape(), (3, 1));
+        assert_eq!(m.sum(Axis::Cols).data, vec![9.0, 12.0]);
+        assert_eq!(m.sum(Axis::Cols).shape(), (1, 2));
+        assert_eq!(m.sum(Axis::All).data, vec![21.0]);
+        assert_eq!(m.prod(Axis::Cols).data, vec![12.0, 48.0]);
+
+        let ints = Mat::from_vec(2, 2, vec![1, 2, 3, 4]);
+        assert_eq!(ints.prod(Axis::All)[(0, 0)], 24);
+    }
+
+    #[test]
+    fn test_cumsum() {
+        let m = sample();
+        assert_eq!(
+            m.cumsum(Axis::Rows).data,
+            vec![1.0, 7.0, 2.0, 6.0, 6.0, 8.0]
+        );
+        assert_eq!(
+            m.cumsum(Axis::Cols).data,
+            vec![1.0, 6.0, 3.0, 10.0, 9.0, 12.0]
+        );
+        assert_eq!(
+            m.cumsum(Axis::All).data,
+            vec![1.0, 7.0, 9.0, 13.0, 19.0, 21.0]
+        );
+    }
+
+    #[test]
+    fn test_min_max() {
+        let m = sample();
+        assert_eq!(m.min(Axis::Rows).data, vec![1.0, 2.0, 2.0]);
+        assert_eq!(m.max(Axis::Cols).data, vec![6.0, 6.0]);
+        assert_eq!(m.max(Axis::All).data, vec![6.0]);
+        assert_eq!(m.argmin(Axis::Cols), vec![(0, 0), (2, 1)]);
+        assert_eq!(m.argmax(Axis::Rows), vec![(0, 1), (1, 1), (2, 0)]);
+        // Ties resolve to the first position in row-major order.
+        assert_eq!(m.argmax(Axis::All), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn test_min_max_nan() {
+        for m in [
+            Mat::from_vec(1, 2, vec![f64::NAN, 1.0]),
+            Mat::from_vec(1, 2, vec![1.0, f64::NAN]),
+        ] {
+            assert!(m.max(Axis::All)[(0, 0)].is_nan());
+            assert!(m.min(Axis::Rows)[(0, 0)].is_nan());
+            assert_eq!(
+                m.max(Axis::Cols).data.iter().filter(|x| x.is_nan()).count(),
+                1
+            );
+        }
+        let m = Mat::from_vec(1, 3, vec![1.0, f64::NAN, f64::NAN]);
+        assert_eq!(m.argmin(Axis::All), vec![(0, 1)]);
+        assert_eq!(m.argmax(Axis::Rows), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn test_min_max_empty() {
+        let m = Mat::<f64>::zeros(2, 0);
+        assert_eq!(m.try_max(Axis::Rows), Err(Error::Empty));
+        assert_eq!(m.try_argmin(Axis::All), Err(Error::Empty));
+        assert_eq!(m.max(Axis::Cols).shape(), (1, 0));
+        assert_eq!(m.sum(Axis::Rows).data, vec![0.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix must not be empty.")]
+    fn test_max_empty_lane_panics() {
+        let _ = Mat::<f64>::from_vec(2, 0, vec![]).max(Axis::Rows);
+    }
+
+    #[test]
+    fn test_mean_var_std() {
+        let m = sample();
+        assert_eq!(m.mean(Axis::Cols).data, vec![3.0, 4.0]);
+        assert_eq!(m.mean(Axis::All).data, vec![3.5]);
+        assert_eq!(m.var(Axis::Cols).data, vec![7.0, 4.0]);
+        assert_eq!(m.std(Axis::Cols).data, vec![7.0_f64.sqrt(), 2.0]);
+        assert_eq!(m.var(Axis::Rows).data, vec![12.5, 2.0, 8.0]);
+
+        let centered = &m - &m.mean(Axis::Cols);
+        assert_eq!(centered.sum(Axis::Cols).data, vec![0.0, 0.0]);
+
+        let single = Mat::from_vec(1, 1, vec![5.0]);
+        assert!(single.var(Axis::Rows)[(0, 0)].is_nan());
+        assert!(single.std(Axis::All)[(0, 0)].is_nan());
+    }
+
+    #[test]
+    fn test_covariance_correlation() {
+        let m = sample();
+        let cov = m.covariance();
+        assert_eq!(cov.data, vec![7.0, -5.0, -5.0, 4.0]);
+        assert_eq!(cov[(0, 0)], m.var(Axis::Cols)[(0, 0)]);
+
+        let corr = m.correlation();
+        let expected = -5.0 / (7.0_f64.sqrt() * 2.0);
+        assert!((corr[(0, 1)] - expected).abs() < 1e-12);
+        assert!((corr[(0, 0)] - 1.0).abs() < 1e-12);
+        assert_eq!(corr[(0, 1)], corr[(1, 0)]);
+
+        let line = Mat::from_vec(3, 2, vec![1.0, 3.0, 2.0, 5.0, 3.0, 7.0]);
+        assert!((line.correlation()[(0, 1)] - 1.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,357 @@
+use super::Mat;
+use crate::Error;
+
+const MAX_SWEEPS: usize = 100;
+
+/// Singular value decomposition `A = U * Σ * Vᵀ`.
+///
+/// `s` holds the singular values in descending order. For the thin variant
+/// `U` is `m x k` and `Vᵀ` is `k x n` with `k = min(m, n)`; the full variant
+/// completes both to square orthogonal matrices.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Svd {
+    pub u: Mat,
+    pub s: Vec<f64>,
+    pub vt: Mat,
+}
+
+impl Mat {
+    pub fn svd(&self) -> Svd {
+        self.try_svd().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Fails with [`Error::NoConvergence`] if the Jacobi sweeps run out,
+    /// which in practice only happens for non-finite entries.
+    pub fn try_svd(&self) -> Result<Svd, Error> {
+        self.compute_svd(false)
+    }
+
+    pub fn svd_full(&self) -> Svd {
+        self.try_svd_full().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_svd_full(&self) -> Result<Svd, Error> {
+        self.compute_svd(true)
+    }
+
+    pub fn rank(&self, tol: f64) -> usize {
+        self.try_rank(tol).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_rank(&self, tol: f64) -> Result<usize, Error> {
+        Ok(self.try_svd()?.rank(tol))
+    }
+
+    pub fn pinv(&self) -> Self {
+        self.try_pinv().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_pinv(&self) -> Result<Self, Error> {
+        Ok(self.try_svd()?.pinv())
+    }
+
+    pub fn cond(&self) -> f64 {
+        self.try_cond().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_cond(&self) -> Result<f64, Error> {
+        Ok(self.try_svd()?.cond())
+    }
+
+    pub fn norm2(&self) -> f64 {
+        self.try_norm2().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_norm2(&self) -> Result<f64, Error> {
+        Ok(self.try_svd()?.norm2())
+    }
+
+    /// Orthonormal basis of the null space, one vector per column.
+    pub fn null_space(&self) -> Self {
+        self.try_null_space().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_null_space(&self) -> Result<Self, Error> {
+        let svd = self.try_svd_full()?;
+        let r = svd.rank(svd.default_tol());
+        let n = self.cols;
+        let mut basis = Mat::from_vec(n, n - r, vec![0.0; n * (n - r)]);
+        for j in r..n {
+            for i in 0..n {
+                basis[(i, j - r)] = svd.vt[(j, i)];
+            }
+        }
+        Ok(basis)
+    }
+
+    fn compute_svd(&self, full: bool) -> Result<Svd, Error> {
+        let (m, n) = self.shape();
+        if m >= n {
+            let (u, s, v) = one_sided_jacobi(self)?;
+            let u = if full { complete_basis(&u, &s, m) } else { u };
+            Ok(Svd {
+                u,
+                s,
+                vt: v.transpose(),
+            })
+        } else {
+            let (v, s, u) = one_sided_jacobi(&self.transpose())?;
+            let v = if full { complete_basis(&v, &s, n) } else { v };
+            Ok(Svd {
+                u,
+                s,
+                vt: v.transpose(),
+            })
+        }
+    }
+}
+
+impl Svd {
+    pub fn sigma(&self) -> Mat {
+        let mut sigma = Mat::zeros(self.u.cols, self.vt.rows);
+        for (i, &s) in self.s.iter().enumerate() {
+            sigma[(i, i)] = s;
+        }
+        sigma
+    }
+
+    /// `max(m, n) * ε * σ_max`, the cutoff below which a singular value is
+    /// treated as zero.
+    pub fn default_tol(&self) -> f64 {
+        let size = self.u.rows.max(self.vt.cols);
+        size as f64 * f64::EPSILON * self.norm2()
+    }
+
+    pub fn rank(&self, tol: f64) -> usize {
+        self.s.iter().filter(|&&s| s > tol).count()
+    }
+
+    pub fn norm2(&self) -> f64 {
+        self.s.first().copied().unwrap_or(0.0)
+    }
+
+    pub fn cond(&self) -> f64 {
+        match self.s.last() {
+            Some(&s_min) if s_min > 0.0 => self.norm2() / s_min,
+            _ => f64::INFINITY,
+        }
+    }
+
+    pub fn pinv(&self) -> Mat {
+        let tol = self.default_tol();
+        let (m, n) = (self.u.rows, self.vt.cols);
+        let mut pinv = Mat::zeros(n, m);
+        for (k, &s) in self.s.iter().enumerate().filter(|&(_, &s)| s > tol) {
+            for i in 0..n {
+                let v = self.vt[(k, i)] / s;
+                for j in 0..m {
+                    pinv[(i, j)] += v * self.u[(j, k)];
+                }
+            }
+        }
+        pinv
+    }
+}
+
+/// One-sided Jacobi SVD of a matrix with at least as many rows as columns.
+/// Returns the thin `U`, the singular values in descending order and the
+/// square `V`.
+fn one_sided_jacobi(a: &Mat) -> Result<(Mat, Vec<f64>, Mat), Error> {
+    let (m, n) = a.shape();
+    // Columns of `A` are the rows of `w`, so that rotations touch contiguous
+    // memory.
+    let mut w = a.transpose();
+    let mut v = Mat::eye(n);
+
+    let mut converged = false;
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let (mut alpha, mut beta, mut gamma) = (0.0, 0.0, 0.0);
+                for i in 0..m {
+                    let (wp, wq) = (w[(p, i)], w[(q, i)]);
+                    alpha += wp * wp;
+                    beta += wq * wq;
+                    gamma += wp * wq;
+                }
+                if gamma == 0.0 || gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = zeta.signum() / (zeta.abs() + zeta.hypot(1.0));
+                let c = 1.0 / t.hypot(1.0);
+                let s = c * t;
+                for i in 0..m {
+                    let (wp, wq) = (w[(p, i)], w[(q, i)]);
+                    w[(p, i)] = c * wp - s * wq;
+                    w[(q, i)] = s * wp + c * wq;
+                }
+                for i in 0..n {
+                    let (vp, vq) = (v[(p, i)], v[(q, i)]);
+                    v[(p, i)] = c * vp - s * vq;
+                    v[(q, i)] = s * vp + c * vq;
+                }
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(Error::NoConvergence);
+    }
+
+    let norms: Vec<f64> = (0..n)
+        .map(|j| (0..m).map(|i| w[(j, i)] * w[(j, i)]).sum::<f64>().sqrt())
+        .collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| norms[j].total_cmp(&norms[i]));
+
+    let s: Vec<f64> = order.iter().map(|&j| norms[j]).collect();
+    let mut u = Mat::zeros(m, n);
+    let mut v_sorted = Mat::zeros(n, n);
+    for (dst, &src) in order.iter().enumerate() {
+        for i in 0..m {
+            if norms[src] > 0.0 {
+                u[(i, dst)] = w[(src, i)] / norms[src];
+            }
+        }
+        for i in 0..n {
+            v_sorted[(i, dst)] = v[(src, i)];
+        }
+    }
+
+    let u = complete_basis(&u, &s, n);
+    Ok((u, s, v_sorted))
+}
+
+/// Keeps the columns of `u` that belong to non-zero singular values and fills
+/// up to `cols` columns with an orthonormal basis of their complement.
+fn complete_basis(u: &Mat, s: &[f64], cols: usize) -> Mat {
+    let m = u.rows;
+    let r = s.iter().filter(|&&s| s > 0.0).count();
+    if r == cols {
+        return u.clone();
+    }
+
+    let mut range = Mat::from_vec(m, r, vec![0.0; m * r]);
+    for i in 0..m {
+        for j in 0..r {
+            range[(i, j)] = u[(i, j)];
+        }
+    }
+    let q = range.qr().q();
+
+    let mut basis = Mat::zeros(m, cols);
+    for i in 0..m {
+        for j in 0..cols {
+            basis[(i, j)] = if j < r { u[(i, j)] } else { q[(i, j)] };
+        }
+    }
+    basis
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_mat_approx_eq;
+
+    fn sample() -> Mat {
+        Mat::from_vec(
+            4,
+            3,
+            vec![2.0, 0.0, 1.0, -1.0, 3.0, 0.0, 0.0, 1.0, 4.0, 1.0, -2.0, 1.0],
+        )
+    }
+
+    #[test]
+    fn test_svd_thin() {
+        for m in [sample(), sample().transpose()] {
+            let svd = m.svd();
+            let k = 3;
+            assert_eq!(svd.u.shape(), (m.rows, k));
+            assert_eq!(svd.vt.shape(), (k, m.cols));
+            assert!(svd.s.windows(2).all(|w| w[0] >= w[1]));
+            assert_mat_approx_eq!(svd.u.transpose().dot(&svd.u), Mat::eye(k), 1e-12);
+            assert_mat_approx_eq!(svd.vt.dot(&svd.vt.transpose()), Mat::eye(k), 1e-12);
+            assert_mat_approx_eq!(svd.u.dot(&svd.sigma()).dot(&svd.vt), m, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_svd_full() {
+        for m in [sample(), sample().transpose()] {
+            let svd = m.svd_full();
+            let (rows, cols) = m.shape();
+            assert_eq!(svd.u.shape(), (rows, rows));
+            assert_eq!(svd.vt.shape(), (cols, cols));
+            assert_mat_approx_eq!(svd.u.transpose().dot(&svd.u), Mat::eye(rows), 1e-12);
+            assert_mat_approx_eq!(svd.vt.dot(&svd.vt.transpose()), Mat::eye(cols), 1e-12);
+            assert_mat_approx_eq!(svd.u.dot(&svd.sigma()).dot(&svd.vt), m, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_svd_known_values() {
+        let m = Mat::from_vec(2, 2, vec![3.0, 0.0, 4.0, 5.0]);
+        let svd = m.svd();
+        assert!((svd.s[0] - 45f64.sqrt()).abs() < 1e-12);
+        assert!((svd.s[1] - 5f64.sqrt()).abs() < 1e-12);
+        assert!((m.norm2() - 45f64.sqrt()).abs() < 1e-12);
+        assert!((m.cond() - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rank_and_null_space() {
+        let m = Mat::from_vec(3, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        assert_eq!(m.rank(1e-10), 2);
+        assert!(m.cond() > 1e15);
+
+        let null = m.null_space();
+        assert_eq!(null.shape(), (3, 1));
+        assert_mat_approx_eq!(m.dot(&null), Mat::zeros(3, 1), 1e-12);
+        assert!((null.transpose().dot(&null)[(0, 0)] - 1.0).abs() < 1e-12);
+
+        assert_eq!(Mat::eye(3).null_space().shape(), (3, 0));
+    }
+
+    #[test]
+    fn test_svd_zero_matrix() {
+        let m = Mat::zeros(3, 2);
+        let svd = m.svd_full();
+        assert_eq!(svd.s, vec![0.0, 0.0]);
+        assert_mat_approx_eq!(svd.u.transpose().dot(&svd.u), Mat::eye(3), 1e-12);
+        assert_eq!(m.rank(0.0), 0);
+        assert_eq!(m.cond(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_pinv() {
+        let m = sample();
+        let pinv = m.pinv();
+        assert_eq!(pinv.shape(), (3, 4));
+        assert_mat_approx_eq!(m.dot(&pinv).dot(&m), m, 1e-12);
+        assert_mat_approx_eq!(pinv.dot(&m), Mat::eye(3), 1e-12);
+
+        let singular = Mat::from_vec(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+        let pinv = singular.pinv();
+        assert_mat_approx_eq!(singular.dot(&pinv).dot(&singular), singular, 1e-12);
+        assert_mat_approx_eq!(pinv.dot(&singular).dot(&pinv), pinv, 1e-12);
+    }
+
+    #[test]
+    fn test_svd_no_convergence() {
+        let m = Mat::filled(2, 2, f64::NAN);
+        assert_eq!(m.try_svd().unwrap_err(), Error::NoConvergence);
+        assert_eq!(m.try_svd_full().unwrap_err(), Error::NoConvergence);
+        assert_eq!(m.try_rank(1e-10), Err(Error::NoConvergence));
+        assert_eq!(m.try_pinv(), Err(Error::NoConvergence));
+        assert_eq!(m.try_cond(), Err(Error::NoConvergence));
+        assert_eq!(m.try_norm2(), Err(Error::NoConvergence));
+        assert_eq!(m.try_null_space(), Err(Error::NoConvergence));
+    }
+}
@@ -0,0 +1,631 @@
+use std::fmt;
+use std::ops;
+use std::ops::Range;
+
+use super::{broadcast_shape, Mat};
+use crate::scalar::Scalar;
+use crate::Error;
+
+/// Borrowed, strided window into a [`Mat`].
+///
+/// Element `(i, j)` lives at `data[i * row_stride + j * col_stride]`, which
+/// lets rows, columns, blocks, the diagonal and the transpose all share the
+/// parent's buffer without copying.
+#[derive(Clone, Copy)]
+pub struct MatView<'a, T> {
+    data: &'a [T],
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+/// Mutable counterpart of [`MatView`].
+pub struct MatViewMut<'a, T> {
+    data: &'a mut [T],
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+/// Range of `data` covered by a `rows x cols` window starting at `start`.
+fn extent(
+    start: usize,
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+) -> Range<usize> {
+    if rows == 0 || cols == 0 {
+        return 0..0;
+    }
+    start..start + (rows - 1) * row_stride + (cols - 1) * col_stride + 1
+}
+
+fn check_range(range: &Range<usize>, bound: usize) {
+    assert!(
+        range.start <= range.end && range.end <= bound,
+        "Range {:?} out of bounds for length {}.",
+        range,
+        bound
+    );
+}
+
+macro_rules! impl_view_common {
+    ($view:ident) => {
+        impl<T> $view<'_, T> {
+            pub fn shape(&self) -> (usize, usize) {
+                (self.rows, self.cols)
+            }
+
+            fn offset(&self, row: usize, col: usize) -> usize {
+                assert!(row < self.rows, "Row index out of bounds.");
+                assert!(col < self.cols, "Column index out of bounds.");
+                row * self.row_stride + col * self.col_stride
+            }
+
+            fn row_range(&self, row: usize) -> (Range<usize>, usize, usize) {
+                assert!(row < self.rows, "Row index out of bounds.");
+                let start = row * self.row_stride;
+                (
+                    extent(start, 1, self.cols, self.row_stride, self.col_stride),
+                    1,
+                    self.cols,
+                )
+            }
+
+            fn col_range(&self, col: usize) -> (Range<usize>, usize, usize) {
+                assert!(col < self.cols, "Column index out of bounds.");
+                let start = col * self.col_stride;
+                (
+                    extent(start, self.rows, 1, self.row_stride, self.col_stride),
+                    self.rows,
+                    1,
+                )
+            }
+
+            fn block_range(&self, rows: &Range<usize>, cols: &Range<usize>) -> Range<usize> {
+                check_range(rows, self.rows);
+                check_range(cols, self.cols);
+                let start = rows.start * self.row_stride + cols.start * self.col_stride;
+                extent(
+                    start,
+                    rows.len(),
+                    cols.len(),
+                    self.row_stride,
+                    self.col_stride,
+                )
+            }
+
+            fn diag_range(&self) -> (Range<usize>, usize) {
+                let n = self.rows.min(self.cols);
+                (extent(0, n, 1, self.row_stride + self.col_stride, 0), n)
+            }
+        }
+
+        impl<T> ops::Index<(usize, usize)> for $view<'_, T> {
+            type Output = T;
+
+            fn index(&self, index: (usize, usize)) -> &T {
+                &self.data[self.offset(index.0, index.1)]
+            }
+        }
+
+        impl<T: fmt::Debug> fmt::Debug for $view<'_, T> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_list()
+                    .entries(
+                        (0..self.rows)
+                            .map(|i| (0..self.cols).map(|j| &self[(i, j)]).collect::<Vec<_>>()),
+                    )
+                    .finish()
+            }
+        }
+    };
+}
+
+impl_view_common!(MatView);
+impl_view_common!(MatViewMut);
+
+impl<'a, T> MatView<'a, T> {
+    pub fn row(&self, row: usize) -> MatView<'a, T> {
+        let (range, rows, cols) = self.row_range(row);
+        self.slice(range, rows, cols, self.row_stride, self.col_stride)
+    }
+
+    pub fn col(&self, col: usize) -> MatView<'a, T> {
+        let (range, rows, cols) = self.col_range(col);
+        self.slice(range, rows, cols, self.row_stride, self.col_stride)
+    }
+
+    pub fn block(&self, rows: Range<usize>, cols: Range<usize>) -> MatView<'a, T> {
+        let range = self.block_range(&rows, &cols);
+        self.slice(
+            range,
+            rows.len(),
+            cols.len(),
+            self.row_stride,
+            self.col_stride,
+        )
+    }
+
+    /// The main diagonal as a column.
+    pub fn diag(&self) -> MatView<'a, T> {
+        let (range, n) = self.diag_range();
+        self.slice(range, n, 1, self.row_stride + self.col_stride, 0)
+    }
+
+    /// Entries in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        let (data, cols) = (self.data, self.cols);
+        let (row_stride, col_stride) = (self.row_stride, self.col_stride);
+        (0..self.rows)
+            .flat_map(move |i| (0..cols).map(move |j| &data[i * row_stride + j * col_stride]))
+    }
+
+    pub fn t(&self) -> MatView<'a, T> {
+        MatView {
+            data: self.data,
+            rows: self.cols,
+            cols: self.rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+        }
+    }
+
+    fn slice(
+        &self,
+        range: Range<usize>,
+        rows: usize,
+        cols: usize,
+        row_stride: usize,
+        col_stride: usize,
+    ) -> MatView<'a, T> {
+        MatView {
+            data: &self.data[range],
+            rows,
+            cols,
+            row_stride,
+            col_stride,
+        }
+    }
+}
+
+impl<T: Scalar> MatView<'_, T> {
+    pub fn to_mat(&self) -> Mat<T> {
+        let data = (0..self.rows)
+            .flat_map(|i| (0..self.cols).map(move |j| (i, j)))
+            .map(|index| self[index])
+            .collect();
+        Mat {
+            data,
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<'a, T> MatViewMut<'a, T> {
+    pub fn as_view(&self) -> MatView<'_, T> {
+        MatView {
+            data: self.data,
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        }
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> MatViewMut<'_, T> {
+        self.reborrow().into_row(row)
+    }
+
+    pub fn col_mut(&mut self, col: usize) -> MatViewMut<'_, T> {
+        self.reborrow().into_col(col)
+    }
+
+    pub fn block_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> MatViewMut<'_, T> {
+        self.reborrow().into_block(rows, cols)
+    }
+
+    pub fn diag_mut(&mut self) -> MatViewMut<'_, T> {
+        self.reborrow().into_diag()
+    }
+
+    pub fn t(self) -> MatViewMut<'a, T> {
+        MatViewMut {
+            data: self.data,
+            rows: self.cols,
+            cols: self.rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+        }
+    }
+
+    fn reborrow(&mut self) -> MatViewMut<'_, T> {
+        MatViewMut {
+            data: self.data,
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        }
+    }
+
+    fn into_row(self, row: usize) -> MatViewMut<'a, T> {
+        let (range, rows, cols) = self.row_range(row);
+        let (row_stride, col_stride) = (self.row_stride, self.col_stride);
+        self.into_slice(range, rows, cols, row_stride, col_stride)
+    }
+
+    fn into_col(self, col: usize) -> MatViewMut<'a, T> {
+        let (range, rows, cols) = self.col_range(col);
+        let (row_stride, col_stride) = (self.row_stride, self.col_stride);
+        self.into_slice(range, rows, cols, row_stride, col_stride)
+    }
+
+    fn into_block(self, rows: Range<usize>, cols: Range<usize>) -> MatViewMut<'a, T> {
+        let range = self.block_range(&rows, &cols);
+        let (row_stride, col_stride) = (self.row_stride, self.col_stride);
+        self.into_slice(range, rows.len(), cols.len(), row_stride, col_stride)
+    }
+
+    fn into_diag(self) -> MatViewMut<'a, T> {
+        let (range, n) = self.diag_range();
+        let stride = self.row_stride + self.col_stride;
+        self.into_slice(range, n, 1, stride, 0)
+    }
+
+    fn into_slice(
+        self,
+        range: Range<usize>,
+        rows: usize,
+        cols: usize,
+        row_stride: usize,
+        col_stride: usize,
+    ) -> MatViewMut<'a, T> {
+        MatViewMut {
+            data: &mut self.data[range],
+            rows,
+            cols,
+            row_stride,
+            col_stride,
+        }
+    }
+}
+
+impl<T: Scalar> MatViewMut<'_, T> {
+    pub fn fill(&mut self, value: T) {
+        self.map_assign(|_| value);
+    }
+
+    /// Copies `src` into the view; `src` must broadcast to the view's shape.
+    pub fn assign(&mut self, src: MatView<'_, T>) {
+        self.zip_assign(src, |_, b| b);
+    }
+
+    pub fn to_mat(&self) -> Mat<T> {
+        self.as_view().to_mat()
+    }
+
+    fn map_assign(&mut self, f: impl Fn(T) -> T) {
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                self[(i, j)] = f(self[(i, j)]);
+            }
+        }
+    }
+
+    /// Updates the view in place with `f(self, other)`, broadcasting `other`
+    /// like [`Mat`]'s compound assignment does.
+    fn zip_assign(&mut self, other: MatView<'_, T>, f: impl Fn(T, T) -> T) {
+        if broadcast_shape(self.shape(), other.shape()) != Ok(self.shape()) {
+            let e = Error::ShapeMismatch {
+                left: self.shape(),
+                right: other.shape(),
+            };
+            panic!("{}", e);
+        }
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                let i_other = if other.rows == 1 { 0 } else { i };
+                let j_other = if other.cols == 1 { 0 } else { j };
+                self[(i, j)] = f(self[(i, j)], other[(i_other, j_other)]);
+            }
+        }
+    }
+}
+
+impl<T> ops::IndexMut<(usize, usize)> for MatViewMut<'_, T> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
+        let offset = self.offset(index.0, index.1);
+        &mut self.data[offset]
+    }
+}
+
+impl<T: Scalar> Mat<T> {
+    pub fn view(&self) -> MatView<'_, T> {
+        MatView {
+            data: &self.data,
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.cols,
+            col_stride: 1,
+        }
+    }
+
+    pub fn view_mut(&mut self) -> MatViewMut<'_, T> {
+        MatViewMut {
+            data: &mut self.data,
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.cols,
+            col_stride: 1,
+        }
+    }
+
+    pub fn row(&self, row: usize) -> MatView<'_, T> {
+        self.view().row(row)
+    }
+
+    pub fn col(&self, col: usize) -> MatView<'_, T> {
+        self.view().col(col)
+    }
+
+    pub fn block(&self, rows: Range<usize>, cols: Range<usize>) -> MatView<'_, T> {
+        self.view().block(rows, cols)
+    }
+
+    pub fn diag(&self) -> MatView<'_, T> {
+        self.view().diag()
+    }
+
+    /// Transposed view, without copying. See [`Mat::transpose`] for an
+    /// owned transpose.
+    pub fn t(&self) -> MatView<'_, T> {
+        self.view().t()
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> MatViewMut<'_, T> {
+        self.view_mut().into_row(row)
+    }
+
+    pub fn col_mut(&mut self, col: usize) -> MatViewMut<'_, T> {
+        self.view_mut().into_col(col)
+    }
+
+    pub fn block_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> MatViewMut<'_, T> {
+        self.view_mut().into_block(rows, cols)
+    }
+
+    pub fn diag_mut(&mut self) -> MatViewMut<'_, T> {
+        self.view_mut().into_diag()
+    }
+}
+
+macro_rules! impl_view_elementwise_op {
+    ($op:ident, $method:ident, $assign:ident, $assign_method:ident) => {
+        impl<T: Scalar> ops::$op<MatView<'_, T>> for MatView<'_, T> {
+            type Output = Mat<T>;
+
+            fn $method(self, other: MatView<'_, T>) -> Mat<T> {
+                ops::$op::$method(self.to_mat(), other)
+            }
+        }
+
+        impl<T: Scalar> ops::$op<&Mat<T>> for MatView<'_, T> {
+            type Output = Mat<T>;
+
+            fn $method(self, other: &Mat<T>) -> Mat<T> {
+                ops::$op::$method(self, other.view())
+            }
+        }
+
+        impl<T: Scalar> ops::$op<MatView<'_, T>> for &Mat<T> {
+            type Output = Mat<T>;
+
+            fn $method(self, other: MatView<'_, T>) -> Mat<T> {
+                ops::$op::$method(self.view(), other)
+            }
+        }
+
+        impl<T: Scalar> ops::$op<MatView<'_, T>> for Mat<T> {
+            type Output = Mat<T>;
+
+            fn $method(mut self, other: MatView<'_, T>) -> Mat<T> {
+                if !self.broadcasts_from(other.shape()) {
+                    return ops::$op::$method(&self, &other.to_mat());
+                }
+                ops::$assign::$assign_method(&mut self, other);
+                self
+            }
+        }
+
+        impl<T: Scalar> ops::$assign<MatView<'_, T>> for Mat<T> {
+            fn $assign_method(&mut self, other: MatView<'_, T>) {
+                ops::$assign::$assign_method(&mut self.view_mut(), other);
+            }
+        }
+
+        impl<T: Scalar> ops::$assign<MatView<'_, T>> for MatViewMut<'_, T> {
+            fn $assign_method(&mut self, other: MatView<'_, T>) {
+                self.zip_assign(other, ops::$op::$method);
+            }
+        }
+
+        impl<T: Scalar> ops::$assign<&Mat<T>> for MatViewMut<'_, T> {
+            fn $assign_method(&mut self, other: &Mat<T>) {
+                ops::$assign::$assign_method(self, other.view());
+            }
+        }
+    };
+}
+
+impl_view_elementwise_op!(Add, add, AddAssign, add_assign);
+impl_view_elementwise_op!(Sub, sub, SubAssign, sub_assign);
+
+impl<T: Scalar> ops::Mul<MatView<'_, T>> for MatView<'_, T> {
+    type Output = Mat<T>;
+
+    fn mul(self, other: MatView<'_, T>) -> Mat<T> {
+        &self.to_mat() * &other.to_mat()
+    }
+}
+
+impl<T: Scalar> ops::Mul<&Mat<T>> for MatView<'_, T> {
+    type Output = Mat<T>;
+
+    fn mul(self, other: &Mat<T>) -> Mat<T> {
+        &self.to_mat() * other
+    }
+}
+
+impl<T: Scalar> ops::Mul<MatView<'_, T>> for &Mat<T> {
+    type Output = Mat<T>;
+
+    fn mul(self, other: MatView<'_, T>) -> Mat<T> {
+        self * &other.to_mat()
+    }
+}
+
+impl<T: Scalar> ops::Mul<T> for MatView<'_, T> {
+    type Output = Mat<T>;
+
+    fn mul(self, scalar: T) -> Mat<T> {
+        self.to_mat() * scalar
+    }
+}
+
+impl<T: Scalar> ops::MulAssign<T> for MatViewMut<'_, T> {
+    fn mul_assign(&mut self, scalar: T) {
+        self.map_assign(|val| val * scalar);
+    }
+}
+
+impl<T: Scalar + ops::Neg<Output = T>> ops::Neg for MatView<'_, T> {
+    type Output = Mat<T>;
+
+    fn neg(self) -> Mat<T> {
+        -self.to_mat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Mat;
+
+    fn sample() -> Mat {
+        Mat::from_vec(3, 4, (1..=12).map(f64::from).collect())
+    }
+
+    #[test]
+    fn test_row_col() {
+        let m = sample();
+        assert_eq!(m.row(1).to_mat().data, vec![5.0, 6.0, 7.0, 8.0]);
+        assert_eq!(m.col(2).to_mat().data, vec![3.0, 7.0, 11.0]);
+        assert_eq!(m.row(1).shape(), (1, 4));
+        assert_eq!(m.col(2).shape(), (3, 1));
+        assert_eq!(m.col(3)[(2, 0)], 12.0);
+    }
+
+    #[test]
+    fn test_block() {
+        let m = sample();
+        let b = m.block(1..3, 1..3);
+        assert_eq!(b.to_mat().data, vec![6.0, 7.0, 10.0, 11.0]);
+        assert_eq!(b.row(1).to_mat().data, vec![10.0, 11.0]);
+        assert_eq!(b.col(0).to_mat().data, vec![6.0, 10.0]);
+        assert_eq!(m.block(3..3, 0..4).shape(), (0, 4));
+    }
+
+    #[test]
+    fn test_diag_and_transpose() {
+        let m = sample();
+        assert_eq!(m.diag().to_mat().data, vec![1.0, 6.0, 11.0]);
+        assert_eq!(m.t().to_mat(), m.transpose());
+        assert_eq!(m.t().row(0).to_mat().data, vec![1.0, 5.0, 9.0]);
+        assert_eq!(m.t().diag().to_mat().data, vec![1.0, 6.0, 11.0]);
+        assert_eq!(m.block(0..2, 1..4).t()[(2, 1)], 8.0);
+    }
+
+    #[test]
+    fn test_views_share_storage() {
+        let m = sample();
+        assert!(std::ptr::eq(&m.row(2)[(0, 0)], &m[(2, 0)]));
+        assert!(std::ptr::eq(&m.t()[(3, 1)], &m[(1, 3)]));
+    }
+
+    #[test]
+    fn test_view_mut() {
+        let mut m = sample();
+        m.row_mut(0).fill(0.0);
+        m.col_mut(3).assign(Mat::ones(3, 1).view());
+        m.diag_mut()[(1, 0)] = -6.0;
+        m.block_mut(1..3, 0..2).t()[(1, 0)] = 42.0;
+        assert_eq!(
+            m.data,
+            vec![0.0, 0.0, 0.0, 1.0, 5.0, 42.0, 7.0, 1.0, 9.0, 10.0, 11.0, 1.0]
+        );
+
+        let mut v = m.view_mut();
+        let mut inner = v.block_mut(1..3, 1..3);
+        inner *= 2.0;
+        assert_eq!(inner.to_mat().data, vec![84.0, 14.0, 20.0, 22.0]);
+    }
+
+    #[test]
+    fn test_view_arithmetic() {
+        let m = sample();
+        let sum = m.row(0) + m.row(1);
+        assert_eq!(sum.data, vec![6.0, 8.0, 10.0, 12.0]);
+        let diff = &m.get_row(2) - m.row(0);
+        assert_eq!(diff.data, vec![8.0; 4]);
+        let sum = m.col(0) + &m.get_col(1);
+        assert_eq!(sum.data, vec![3.0, 11.0, 19.0]);
+
+        let gram = m.t() * m.view();
+        assert_eq!(gram, &m.transpose() * &m);
+        assert_eq!(&m * m.t(), &m * &m.transpose());
+        assert_eq!((m.row(0) * 2.0).data, vec![2.0, 4.0, 6.0, 8.0]);
+        assert_eq!((-m.col(0)).data, vec![-1.0, -5.0, -9.0]);
+
+        let mut acc = Mat::zeros(1, 4);
+        acc += m.row(0);
+        acc -= m.row(1);
+        assert_eq!(acc.data, vec![-4.0; 4]);
+
+        let mut n = m.clone();
+        let mut first = n.row_mut(0);
+        first += m.row(2);
+        let mut second = n.row_mut(1);
+        second -= &m.get_row(1);
+        assert_eq!(n.get_row(0).data, vec![10.0, 12.0, 14.0, 16.0]);
+        assert_eq!(n.get_row(1).data, vec![0.0; 4]);
+    }
+
+    #[test]
+    fn test_view_broadcasting() {
+        let m = sample();
+        let shifted = &m + m.row(0);
+        assert_eq!(shifted, &m + &m.get_row(0));
+        let centered = m.clone() - m.col(0);
+        assert_eq!(centered, &m - &m.get_col(0));
+        assert_eq!(m.row(0) + &m, &m.get_row(0) + &m);
+        assert_eq!(m.row(0) + m.col(0), &m.get_row(0) + &m.get_col(0));
+
+        let mut n = m.clone();
+        n -= m.row(1);
+        assert_eq!(n, &m - &m.get_row(1));
+        let mut block = n.block_mut(0..2, 0..2);
+        block += m.col(3).block(0..2, 0..1);
+        assert_eq!(n[(1, 1)], m[(1, 1)] - m[(1, 1)] + m[(1, 3)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix shapes 1x4 and 2x2 are not compatible.")]
+    fn test_view_shape_mismatch() {
+        let m = sample();
+        let _ = m.row(0) + m.block(0..2, 0..2);
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt;
+use std::ops;
+
+/// Element type of a [`Mat`](crate::matrix::Mat).
+///
+/// Implemented for the primitive integer and floating point types and for
+/// [`Complex`](crate::complex::Complex). With the `parallel` feature, which
+/// shares matrix data across threads, a scalar must also be `Send + Sync`.
+pub trait Scalar:
+    Copy
+    + PartialEq
+    + MaybeSendSync
+    + fmt::Debug
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+    + ops::Div<Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+
+    /// Magnitude as an `f64`: the absolute value of a real number and the
+    /// modulus of a complex one.
+    fn abs(self) -> f64;
+}
+
+// `Send + Sync` with the `parallel` feature and no bound at all without it.
+// Only public because it appears in the bounds of `Scalar`, which documents
+// the requirement; the blanket impls leave nothing to implement by hand.
+#[doc(hidden)]
+#[cfg(feature = "parallel")]
+pub trait MaybeSendSync: Send + Sync {}
+
+#[cfg(feature = "parallel")]
+impl<T: Send + Sync> MaybeSendSync for T {}
+
+#[doc(hidden)]
+#[cfg(not(feature = "parallel"))]
+pub trait MaybeSendSync {}
+
+#[cfg(not(feature = "parallel"))]
+impl<T> MaybeSendSync for T {}
+
+macro_rules! impl_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                fn zero() -> Self {
+                    0 as $t
+                }
+
+                fn one() -> Self {
+                    1 as $t
+                }
+
+                fn abs(self) -> f64 {
+                    (self as f64).abs()
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);